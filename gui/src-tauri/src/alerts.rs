@@ -0,0 +1,242 @@
+use crate::api::ApiClient;
+use crate::error::BackendError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Which metric an [`AlertRule`] watches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AlertDefinition {
+    LockedCapital,
+    PotentialLossPercentage,
+    DeadStockItemCount,
+    InventoryValueDrop,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Comparison {
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+impl Comparison {
+    fn holds(self, observed: f64, threshold: f64) -> bool {
+        match self {
+            Comparison::GreaterThan => observed > threshold,
+            Comparison::GreaterThanOrEqual => observed >= threshold,
+            Comparison::LessThan => observed < threshold,
+            Comparison::LessThanOrEqual => observed <= threshold,
+        }
+    }
+}
+
+/// A threshold to watch on dead-stock/inventory metrics. `window` doubles as
+/// the cooldown before this rule can fire again and, for `InventoryValueDrop`,
+/// as the minimum time between the baseline snapshot and the comparison.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertRule {
+    pub definition: AlertDefinition,
+    pub threshold: f64,
+    pub comparison: Comparison,
+    pub window: Duration,
+}
+
+impl AlertRule {
+    fn key(&self) -> String {
+        format!("{:?}:{:?}:{}", self.definition, self.comparison, self.threshold)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alert {
+    pub rule: AlertRule,
+    pub observed_value: f64,
+    pub triggered_at: DateTime<Utc>,
+    pub affected_items: Vec<String>,
+}
+
+struct RuleMemory {
+    last_fired: Option<DateTime<Utc>>,
+    last_baseline: Option<(DateTime<Utc>, f64)>,
+}
+
+/// Evaluates [`AlertRule`]s against the live dead-stock/dashboard summaries,
+/// remembering per-rule cooldowns and (for `InventoryValueDrop`) the last
+/// baseline value across calls, and fanning fired alerts out to any
+/// registered sinks.
+#[derive(Default)]
+pub struct AlertEngine {
+    memory: Mutex<HashMap<String, RuleMemory>>,
+    sinks: Mutex<Vec<mpsc::UnboundedSender<Alert>>>,
+}
+
+impl AlertEngine {
+    /// Registers a new sink; every alert fired from now on is also sent here.
+    /// A sink whose receiver has been dropped is pruned on the next fire.
+    pub fn register_sink(&self) -> mpsc::UnboundedReceiver<Alert> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.sinks.lock().unwrap().push(tx);
+        rx
+    }
+
+    fn dispatch(&self, alert: &Alert) {
+        self.sinks.lock().unwrap().retain(|sink| sink.send(alert.clone()).is_ok());
+    }
+
+    pub async fn evaluate(&self, client: &ApiClient, rules: &[AlertRule]) -> Result<Vec<Alert>, BackendError> {
+        if rules.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let dead_stock = client.get_dead_stock_summary().await?;
+        let dashboard = client.get_dashboard_metrics().await?;
+        let now = Utc::now();
+
+        let mut fired = Vec::new();
+        for rule in rules {
+            let affected_items: Vec<String> = dead_stock
+                .top_priorities
+                .iter()
+                .map(|item| item.item_id.clone())
+                .collect();
+
+            let observed = match rule.definition {
+                AlertDefinition::LockedCapital => Some(dead_stock.financial_impact.locked_capital),
+                AlertDefinition::PotentialLossPercentage => Some(dead_stock.financial_impact.loss_percentage),
+                AlertDefinition::DeadStockItemCount => Some(dead_stock.total_items_at_risk as f64),
+                AlertDefinition::InventoryValueDrop => {
+                    self.drop_since_baseline(rule, dashboard.total_inventory_value, now)
+                }
+            };
+
+            let Some(observed_value) = observed else { continue };
+            if !rule.comparison.holds(observed_value, rule.threshold) {
+                continue;
+            }
+
+            let mut memory = self.memory.lock().unwrap();
+            let entry = memory.entry(rule.key()).or_insert(RuleMemory { last_fired: None, last_baseline: None });
+            let cooling_down = entry
+                .last_fired
+                .map(|last| now - last < chrono::Duration::from_std(rule.window).unwrap_or_default())
+                .unwrap_or(false);
+            if cooling_down {
+                continue;
+            }
+            entry.last_fired = Some(now);
+            drop(memory);
+
+            let alert = Alert {
+                rule: rule.clone(),
+                observed_value,
+                triggered_at: now,
+                affected_items,
+            };
+            self.dispatch(&alert);
+            fired.push(alert);
+        }
+
+        Ok(fired)
+    }
+
+    /// Returns the drop from the last recorded baseline once `window` has
+    /// elapsed. `current` becomes the new baseline on the first call and
+    /// whenever a drop is reported; inside the window the earlier baseline
+    /// is kept.
+    fn drop_since_baseline(&self, rule: &AlertRule, current: f64, now: DateTime<Utc>) -> Option<f64> {
+        let mut memory = self.memory.lock().unwrap();
+        let entry = memory.entry(rule.key()).or_insert(RuleMemory { last_fired: None, last_baseline: None });
+
+        let window = chrono::Duration::from_std(rule.window).unwrap_or_default();
+        let drop = match entry.last_baseline {
+            Some((baseline_at, baseline_value)) if now - baseline_at >= window => {
+                Some(baseline_value - current)
+            }
+            Some(_) => None,
+            None => None,
+        };
+
+        if drop.is_some() || entry.last_baseline.is_none() {
+            entry.last_baseline = Some((now, current));
+        }
+        drop
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(window_secs: u64) -> AlertRule {
+        AlertRule {
+            definition: AlertDefinition::InventoryValueDrop,
+            threshold: 0.0,
+            comparison: Comparison::GreaterThan,
+            window: Duration::from_secs(window_secs),
+        }
+    }
+
+    #[test]
+    fn comparison_holds_matches_each_operator() {
+        assert!(Comparison::GreaterThan.holds(5.0, 1.0));
+        assert!(!Comparison::GreaterThan.holds(1.0, 1.0));
+        assert!(Comparison::GreaterThanOrEqual.holds(1.0, 1.0));
+        assert!(Comparison::LessThan.holds(0.0, 1.0));
+        assert!(!Comparison::LessThan.holds(1.0, 1.0));
+        assert!(Comparison::LessThanOrEqual.holds(1.0, 1.0));
+    }
+
+    #[test]
+    fn drop_since_baseline_records_a_baseline_on_first_call() {
+        let engine = AlertEngine::default();
+        let rule = rule(60);
+        let now = Utc::now();
+
+        assert_eq!(engine.drop_since_baseline(&rule, 1000.0, now), None);
+    }
+
+    #[test]
+    fn drop_since_baseline_stays_silent_until_the_window_elapses() {
+        let engine = AlertEngine::default();
+        let rule = rule(60);
+        let t0 = Utc::now();
+
+        assert_eq!(engine.drop_since_baseline(&rule, 1000.0, t0), None);
+        // Still inside the cooldown window: no comparison yet, and the
+        // original baseline must not have been overwritten.
+        let t1 = t0 + chrono::Duration::seconds(30);
+        assert_eq!(engine.drop_since_baseline(&rule, 900.0, t1), None);
+    }
+
+    #[test]
+    fn drop_since_baseline_reports_the_drop_once_the_window_elapses() {
+        let engine = AlertEngine::default();
+        let rule = rule(60);
+        let t0 = Utc::now();
+
+        assert_eq!(engine.drop_since_baseline(&rule, 1000.0, t0), None);
+        let t1 = t0 + chrono::Duration::seconds(61);
+        assert_eq!(engine.drop_since_baseline(&rule, 700.0, t1), Some(300.0));
+    }
+
+    #[test]
+    fn drop_since_baseline_rebaselines_after_reporting_a_drop() {
+        let engine = AlertEngine::default();
+        let rule = rule(60);
+        let t0 = Utc::now();
+
+        assert_eq!(engine.drop_since_baseline(&rule, 1000.0, t0), None);
+        let t1 = t0 + chrono::Duration::seconds(61);
+        assert_eq!(engine.drop_since_baseline(&rule, 700.0, t1), Some(300.0));
+
+        // The baseline is now 700.0 at t1; within the next window there's
+        // nothing to report yet.
+        let t2 = t1 + chrono::Duration::seconds(10);
+        assert_eq!(engine.drop_since_baseline(&rule, 650.0, t2), None);
+    }
+}
@@ -1,16 +1,33 @@
-use reqwest::Client;
+use futures::stream::{self, StreamExt};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
+use crate::alerts::{Alert, AlertEngine, AlertRule};
+use crate::auth::{Credentials, LoginResponse, Session};
+use crate::cache::ResponseCache;
 use crate::commands::{StockXListingRequest, StockXListingResponse};
+use crate::error::BackendError;
+use crate::rate_limit::{retry_after_delay, RateLimiter, RetryPolicy};
+use crate::transport::{HttpRequest, ReqwestExecutor, RequestExecutor};
 
 
-#[derive(Debug, Clone)]
-pub struct ApiClient {
-    client: Client,
+/// Generic over the transport (`E`) so callers can inject a mock or custom
+/// `RequestExecutor` (see [`crate::transport`]); every constructor except
+/// [`ApiClient::with_executor`] uses the default reqwest-backed one.
+#[derive(Clone)]
+pub struct ApiClient<E: RequestExecutor + Clone = ReqwestExecutor> {
+    executor: E,
     base_url: String,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
+    credentials: Option<Credentials>,
+    session: Arc<RwLock<Option<Session>>>,
+    alerts: Arc<AlertEngine>,
+    cache: Arc<ResponseCache>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -31,7 +48,7 @@ pub struct HealthStatus {
     pub components: HashMap<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct InventoryItem {
     pub id: Uuid,
     pub product_id: Option<Uuid>,
@@ -50,6 +67,43 @@ pub struct InventoryItem {
 }
 
 
+/// Result of a delta sync: everything created or updated since the
+/// `server_knowledge` cursor the caller last persisted, plus the ids of
+/// anything deleted in that window and the new cursor to persist.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InventoryDelta {
+    pub upserts: Vec<InventoryItem>,
+    pub deleted_ids: Vec<Uuid>,
+    pub server_knowledge: u64,
+}
+
+/// Chunk size for batched endpoint calls — keeps request URLs (ids are sent
+/// comma-joined in a query param) well under typical server/proxy limits.
+const MAX_BATCH_SIZE: usize = 500;
+/// How many chunk requests a batched call keeps in flight at once.
+const PARALLEL_REQUESTS: usize = 10;
+
+/// A chunk that failed during a batched call, along with the ids it covered
+/// so a caller can retry or report just the affected subset.
+#[derive(Debug, Serialize)]
+pub struct BatchFailure {
+    pub ids: Vec<String>,
+    pub error: BackendError,
+}
+
+/// Result of a batched call: every item fetched successfully, plus the
+/// chunks that failed. A partial failure still returns whatever succeeded
+/// instead of discarding the whole batch.
+#[derive(Debug, Serialize)]
+pub struct BatchResult<T> {
+    pub items: Vec<T>,
+    pub failures: Vec<BatchFailure>,
+}
+
+fn chunk_ids(ids: &[String], size: usize) -> Vec<Vec<String>> {
+    ids.chunks(size).map(|chunk| chunk.to_vec()).collect()
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProductStats {
     pub total_products: i64,
@@ -175,6 +229,72 @@ pub struct EnrichmentResponse {
     pub target_products: String,
 }
 
+/// Server-recognized pricing strategies. Methods taking a strategy accept
+/// `impl Into<String>`, so both `PricingStrategy::Aggressive` and a raw
+/// `String` work — the enum just rules out a typo producing a silent empty
+/// result from the backend's vocabulary match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PricingStrategy {
+    Conservative,
+    Balanced,
+    Aggressive,
+    MarketFollowing,
+}
+
+impl From<PricingStrategy> for String {
+    fn from(strategy: PricingStrategy) -> Self {
+        match strategy {
+            PricingStrategy::Conservative => "conservative",
+            PricingStrategy::Balanced => "balanced",
+            PricingStrategy::Aggressive => "aggressive",
+            PricingStrategy::MarketFollowing => "market_following",
+        }
+        .to_string()
+    }
+}
+
+/// Dead-stock risk tiers, matching [`RiskLevelDefinition`]'s vocabulary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl From<RiskLevel> for String {
+    fn from(level: RiskLevel) -> Self {
+        match level {
+            RiskLevel::Low => "low",
+            RiskLevel::Medium => "medium",
+            RiskLevel::High => "high",
+            RiskLevel::Critical => "critical",
+        }
+        .to_string()
+    }
+}
+
+/// Status filter for `get_stockx_listings`/`get_alias_listings`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListingStatus {
+    Active,
+    Pending,
+    Sold,
+    Cancelled,
+}
+
+impl From<ListingStatus> for String {
+    fn from(status: ListingStatus) -> Self {
+        match status {
+            ListingStatus::Active => "active",
+            ListingStatus::Pending => "pending",
+            ListingStatus::Sold => "sold",
+            ListingStatus::Cancelled => "cancelled",
+        }
+        .to_string()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PricingRequest {
     pub product_id: String,
@@ -210,6 +330,24 @@ pub struct MarketAnalysis {
     pub recommended_action: String,
 }
 
+/// Which historical price sample to resolve for a point-in-time lookup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "at")]
+pub enum RequestTime {
+    Latest,
+    FirstAfter(DateTime<Utc>),
+    ClosestTo(DateTime<Utc>),
+}
+
+/// A [`MarketAnalysis`] sample plus the timestamp it was actually observed
+/// at, so backtests can tell a `FirstAfter`/`ClosestTo` lookup apart from
+/// the timestamp they asked for.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MarketAnalysisAt {
+    pub analysis: MarketAnalysis,
+    pub observed_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PricingInsights {
     pub timestamp: String,
@@ -617,31 +755,184 @@ pub struct PredictiveInsightsSummary {
     pub next_analysis_at: String,
 }
 
-impl ApiClient {
+impl ApiClient<ReqwestExecutor> {
     pub fn new(base_url: String) -> Self {
+        Self::new_with_config(base_url, RateLimiter::default(), RetryPolicy::default())
+    }
+
+    pub fn new_with_config(base_url: String, rate_limiter: RateLimiter, retry_policy: RetryPolicy) -> Self {
+        Self::with_executor(base_url, ReqwestExecutor::default(), rate_limiter, retry_policy)
+    }
+}
+
+impl<E: RequestExecutor + Clone> ApiClient<E> {
+    /// Builds a client around a custom [`RequestExecutor`] — a mock for unit
+    /// tests, middleware that adds tracing, a proxy, etc — instead of the
+    /// default reqwest-backed one.
+    pub fn with_executor(base_url: String, executor: E, rate_limiter: RateLimiter, retry_policy: RetryPolicy) -> Self {
         Self {
-            client: Client::new(),
+            executor,
             base_url,
+            rate_limiter: Arc::new(rate_limiter),
+            retry_policy,
+            credentials: None,
+            session: Arc::new(RwLock::new(None)),
+            alerts: Arc::new(AlertEngine::default()),
+            cache: Arc::new(ResponseCache::default()),
+        }
+    }
+
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Exchanges `UsernamePassword` credentials for a session token via a
+    /// login POST (`ApiKey`/`Bearer` are used as the token directly, with no
+    /// request) and caches it behind `Arc<RwLock<..>>`, so every clone of
+    /// this client (they all share the same `Arc`) sees the refreshed session
+    /// immediately.
+    pub async fn authenticate(&self) -> Result<(), BackendError> {
+        let session = match &self.credentials {
+            Some(Credentials::ApiKey(key)) => Session {
+                token: key.clone(),
+                expires_at: Utc::now() + chrono::Duration::days(365),
+            },
+            Some(Credentials::Bearer(token)) => Session {
+                token: token.clone(),
+                expires_at: Utc::now() + chrono::Duration::days(365),
+            },
+            Some(Credentials::UsernamePassword { username, password }) => {
+                let url = format!("{}/auth/login", self.base_url);
+                let request = HttpRequest::post(&url)
+                    .with_json(&serde_json::json!({ "username": username, "password": password }));
+                let response = self.executor.execute(request).await?;
+                let login: LoginResponse = self.decode(response)?;
+                Session::from_login(login)
+            }
+            None => return Ok(()),
+        };
+
+        *self.session.write().unwrap() = Some(session);
+        Ok(())
+    }
+
+    async fn apply_auth(&self, request: HttpRequest) -> Result<HttpRequest, BackendError> {
+        if self.credentials.is_none() {
+            return Ok(request);
+        }
+
+        let needs_refresh = match self.session.read().unwrap().as_ref() {
+            Some(session) => session.needs_refresh(),
+            None => true,
+        };
+        if needs_refresh {
+            self.authenticate().await?;
+        }
+
+        let token = self.session.read().unwrap().as_ref().map(|s| s.token.clone());
+        Ok(match token {
+            Some(token) => request.with_header("Authorization", format!("Bearer {}", token)),
+            None => request,
+        })
+    }
+
+    /// Runs `request` through the rate limiter, then sends it, retrying with
+    /// exponential backoff and jitter on `429`/`502`/`503` and transport
+    /// timeouts (honoring a `Retry-After` header when the server sends one).
+    /// A `401` triggers one re-authentication + retry before giving up.
+    /// Every endpoint method below builds its request and delegates here, so
+    /// they all get this behavior without repeating it.
+    async fn execute_with_retry(&self, request: HttpRequest) -> Result<crate::transport::HttpResponse, BackendError> {
+        let mut attempt = 0;
+        let mut reauthenticated = false;
+        loop {
+            self.rate_limiter.acquire().await;
+
+            let attempt_request = self.apply_auth(request.clone()).await?;
+
+            match self.executor.execute(attempt_request).await {
+                Ok(response) if response.status == 401 && !reauthenticated && self.credentials.is_some() => {
+                    self.authenticate().await?;
+                    reauthenticated = true;
+                    continue;
+                }
+                Ok(response) => {
+                    if self.retry_policy.should_retry(attempt, Some(response.status), false) {
+                        let delay = retry_after_delay(&response).unwrap_or_else(|| self.retry_policy.backoff(attempt));
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                        continue;
+                    }
+                    return Ok(response);
+                }
+                Err(e) if self.retry_policy.should_retry(attempt, None, matches!(e, crate::transport::TransportError::Timeout)) => {
+                    tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Decodes a 2xx response body as `T`. On a non-2xx status, attempts to
+    /// parse the server's `{ "error": { "code": ..., "message": ... } }`
+    /// envelope and returns a typed [`BackendError::Api`] instead of letting
+    /// the mismatched shape fail as a generic deserialize error.
+    fn decode<T: DeserializeOwned>(&self, response: crate::transport::HttpResponse) -> Result<T, BackendError> {
+        if response.status >= 400 {
+            return Err(self.api_error(response));
         }
+        Ok(response.json()?)
     }
 
-    pub async fn health_check(&self) -> Result<HealthStatus, reqwest::Error> {
+    fn api_error(&self, response: crate::transport::HttpResponse) -> BackendError {
+        #[derive(Deserialize)]
+        struct ErrorEnvelope {
+            error: ErrorBody,
+        }
+        #[derive(Deserialize)]
+        struct ErrorBody {
+            #[serde(default)]
+            code: Option<String>,
+            #[serde(default)]
+            message: Option<String>,
+        }
+
+        match response.json::<ErrorEnvelope>() {
+            Ok(envelope) => BackendError::Api {
+                status: response.status,
+                code: envelope.error.code,
+                message: envelope.error.message.unwrap_or_else(|| response.text()),
+            },
+            Err(_) => BackendError::Api {
+                status: response.status,
+                code: None,
+                message: response.text(),
+            },
+        }
+    }
+
+    pub async fn health_check(&self) -> Result<HealthStatus, BackendError> {
         let url = format!("{}/health", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let health: HealthStatus = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let health: HealthStatus = self.decode(response)?;
         Ok(health)
     }
 
-    pub async fn get_inventory_items(&self, limit: Option<i32>) -> Result<Vec<InventoryItem>, reqwest::Error> {
+    /// Full-snapshot fetch. Callers that want to track changes over time
+    /// should treat this as seeding `server_knowledge = 0` and switch to
+    /// [`ApiClient::get_inventory_delta`] for subsequent refreshes.
+    pub async fn get_inventory_items(&self, limit: Option<i32>) -> Result<Vec<InventoryItem>, BackendError> {
         let mut url = format!("{}/api/v1/inventory", self.base_url);
         if let Some(limit) = limit {
             url = format!("{}?limit={}", url, limit);
         }
         
-        let response = self.client.get(&url).send().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
         
         // Parse the response which contains items and pagination info
-        let response_json: serde_json::Value = response.json().await?;
+        let response_json: serde_json::Value = self.decode(response)?;
         
         // Extract just the items array from the response
         if let Some(items_value) = response_json.get("items") {
@@ -670,31 +961,48 @@ impl ApiClient {
         }
     }
 
-    pub async fn get_product_stats(&self) -> Result<ProductStats, reqwest::Error> {
+    /// Incremental sync: fetches only items created/updated/deleted since
+    /// `last_knowledge`, following the `last_knowledge_of_server` cursor
+    /// convention used by budgeting-style sync APIs. Pass `None` (sent as
+    /// `0`, the cursor a full [`ApiClient::get_inventory_items`] snapshot
+    /// corresponds to) to bootstrap. The returned `server_knowledge`
+    /// must be persisted by the caller and passed back on the next call.
+    pub async fn get_inventory_delta(&self, last_knowledge: Option<u64>) -> Result<InventoryDelta, BackendError> {
+        let url = format!(
+            "{}/api/v1/inventory/delta?last_knowledge_of_server={}",
+            self.base_url,
+            last_knowledge.unwrap_or(0)
+        );
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let delta: InventoryDelta = self.decode(response)?;
+        Ok(delta)
+    }
+
+    pub async fn get_product_stats(&self) -> Result<ProductStats, BackendError> {
         let url = format!("{}/api/v1/products/stats", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let stats: ProductStats = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let stats: ProductStats = self.decode(response)?;
         Ok(stats)
     }
 
-    pub async fn import_stockx_data(&self, request: ImportRequest) -> Result<ImportResponse, reqwest::Error> {
+    pub async fn import_stockx_data(&self, request: ImportRequest) -> Result<ImportResponse, BackendError> {
         let url = format!("{}/api/v1/integration/stockx/import", self.base_url);
-        let response = self.client.post(&url).json(&request).send().await?;
-        let import_response: ImportResponse = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url).with_json(&request)).await?;
+        let import_response: ImportResponse = self.decode(response)?;
         Ok(import_response)
     }
 
-    pub async fn get_import_status(&self, batch_id: Uuid) -> Result<ImportStatus, reqwest::Error> {
+    pub async fn get_import_status(&self, batch_id: Uuid) -> Result<ImportStatus, BackendError> {
         let url = format!("{}/api/v1/integration/import/{}/status", self.base_url, batch_id);
-        let response = self.client.get(&url).send().await?;
-        let status: ImportStatus = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let status: ImportStatus = self.decode(response)?;
         Ok(status)
     }
 
-    pub async fn get_dashboard_metrics(&self) -> Result<DashboardMetrics, reqwest::Error> {
+    pub async fn get_dashboard_metrics(&self) -> Result<DashboardMetrics, BackendError> {
         let url = format!("{}/api/v1/dashboard/metrics", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let api_metrics: ApiDashboardMetrics = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let api_metrics: ApiDashboardMetrics = self.decode(response)?;
         
         // Convert API response to simplified DashboardMetrics
         let metrics = DashboardMetrics {
@@ -709,15 +1017,15 @@ impl ApiClient {
         Ok(metrics)
     }
 
-    pub async fn run_database_query(&self, query: String) -> Result<Vec<HashMap<String, Value>>, reqwest::Error> {
+    pub async fn run_database_query(&self, query: String) -> Result<Vec<HashMap<String, Value>>, BackendError> {
         let url = format!("{}/api/v1/admin/query", self.base_url);
         let payload = serde_json::json!({"query": query});
-        let response = self.client.post(&url).json(&payload).send().await?;
-        let results: Vec<HashMap<String, Value>> = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url).with_json(&payload)).await?;
+        let results: Vec<HashMap<String, Value>> = self.decode(response)?;
         Ok(results)
     }
 
-    pub async fn export_data_csv(&self, table: String, filters: Option<HashMap<String, String>>) -> Result<String, reqwest::Error> {
+    pub async fn export_data_csv(&self, table: String, filters: Option<HashMap<String, String>>) -> Result<String, BackendError> {
         let mut url = format!("{}/api/v1/export/csv/{}", self.base_url, table);
         
         if let Some(filters) = filters {
@@ -730,19 +1038,19 @@ impl ApiClient {
             }
         }
         
-        let response = self.client.get(&url).send().await?;
-        let csv_data = response.text().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let csv_data = response.text();
         Ok(csv_data)
     }
 
-    pub async fn get_enrichment_status(&self) -> Result<EnrichmentStatusResponse, reqwest::Error> {
+    pub async fn get_enrichment_status(&self) -> Result<EnrichmentStatusResponse, BackendError> {
         let url = format!("{}/api/v1/products/enrichment/status", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let status: EnrichmentStatusResponse = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let status: EnrichmentStatusResponse = self.decode(response)?;
         Ok(status)
     }
 
-    pub async fn start_product_enrichment(&self, product_ids: Option<Vec<String>>) -> Result<EnrichmentResponse, reqwest::Error> {
+    pub async fn start_product_enrichment(&self, product_ids: Option<Vec<String>>) -> Result<EnrichmentResponse, BackendError> {
         let mut url = format!("{}/api/v1/products/enrich", self.base_url);
         
         if let Some(ids) = product_ids {
@@ -755,76 +1063,135 @@ impl ApiClient {
             }
         }
         
-        let response = self.client.post(&url).send().await?;
-        let enrichment_response: EnrichmentResponse = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url)).await?;
+        let enrichment_response: EnrichmentResponse = self.decode(response)?;
         Ok(enrichment_response)
     }
 
-    pub async fn get_pricing_recommendation(&self, request: PricingRequest) -> Result<PricingRecommendation, reqwest::Error> {
+    pub async fn get_pricing_recommendation(&self, request: PricingRequest) -> Result<PricingRecommendation, BackendError> {
         let url = format!("{}/api/v1/pricing/recommend", self.base_url);
-        let response = self.client.post(&url).json(&request).send().await?;
-        let recommendation: PricingRecommendation = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url).with_json(&request)).await?;
+        let recommendation: PricingRecommendation = self.decode(response)?;
         Ok(recommendation)
     }
 
-    pub async fn get_market_analysis(&self, product_id: String) -> Result<MarketAnalysis, reqwest::Error> {
+    pub async fn get_market_analysis(&self, product_id: String) -> Result<MarketAnalysis, BackendError> {
         let url = format!("{}/api/v1/pricing/market-analysis/{}", self.base_url, product_id);
-        let response = self.client.get(&url).send().await?;
-        let analysis: MarketAnalysis = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let analysis: MarketAnalysis = self.decode(response)?;
         Ok(analysis)
     }
 
-    pub async fn get_pricing_insights(&self) -> Result<PricingInsights, reqwest::Error> {
+    /// Resolves a historical `MarketAnalysis` sample so pricing backtests can
+    /// replay conditions as of a point in time instead of only the present.
+    /// `FirstAfter`/`ClosestTo` are forwarded to the backend's price-history
+    /// endpoint, which picks the earliest sample `>= t` or the one closest to
+    /// `t` respectively; a `404` there means no sample exists in range.
+    pub async fn get_market_analysis_at(
+        &self,
+        product_id: String,
+        at: RequestTime,
+    ) -> Result<MarketAnalysisAt, BackendError> {
+        let mut url = format!("{}/api/v1/pricing/market-analysis/{}/at", self.base_url, product_id);
+        match at {
+            RequestTime::Latest => {}
+            RequestTime::FirstAfter(t) => {
+                url = format!("{}?mode=first_after&at={}", url, t.to_rfc3339());
+            }
+            RequestTime::ClosestTo(t) => {
+                url = format!("{}?mode=closest_to&at={}", url, t.to_rfc3339());
+            }
+        }
+
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+
+        if response.status == 404 {
+            return Err(BackendError::NoSampleInRange { product_id });
+        }
+
+        let analysis_at: MarketAnalysisAt = self.decode(response)?;
+        Ok(analysis_at)
+    }
+
+    pub async fn get_pricing_insights(&self) -> Result<PricingInsights, BackendError> {
         let url = format!("{}/api/v1/pricing/insights", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let insights: PricingInsights = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let insights: PricingInsights = self.decode(response)?;
         Ok(insights)
     }
 
-    pub async fn get_pricing_strategies(&self) -> Result<HashMap<String, Value>, reqwest::Error> {
+    /// Like [`Self::get_pricing_insights`], but returns a cached value if one
+    /// younger than `max_age` exists instead of refetching.
+    pub async fn get_pricing_insights_cached(&self, max_age: std::time::Duration) -> Result<PricingInsights, BackendError> {
+        let key = "pricing_insights".to_string();
+        if let Some(cached) = self.cache.get_if_fresh(&key, max_age) {
+            return Ok(cached);
+        }
+        let insights = self.get_pricing_insights().await?;
+        self.cache.insert(key, &insights);
+        Ok(insights)
+    }
+
+    pub async fn get_pricing_strategies(&self) -> Result<HashMap<String, Value>, BackendError> {
         let url = format!("{}/api/v1/pricing/strategies", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let strategies: HashMap<String, Value> = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let strategies: HashMap<String, Value> = self.decode(response)?;
         Ok(strategies)
     }
 
-    pub async fn generate_sales_forecast(&self, request: ForecastRequest) -> Result<ForecastAnalysis, reqwest::Error> {
+    pub async fn generate_sales_forecast(&self, request: ForecastRequest) -> Result<ForecastAnalysis, BackendError> {
         let url = format!("{}/api/v1/analytics/forecast/sales", self.base_url);
-        let response = self.client.post(&url).json(&request).send().await?;
-        let forecast: ForecastAnalysis = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url).with_json(&request)).await?;
+        let forecast: ForecastAnalysis = self.decode(response)?;
         Ok(forecast)
     }
 
-    pub async fn get_market_trends(&self, days_back: Option<i32>) -> Result<Vec<MarketTrend>, reqwest::Error> {
+    pub async fn get_market_trends(&self, days_back: Option<i32>) -> Result<Vec<MarketTrend>, BackendError> {
         let mut url = format!("{}/api/v1/analytics/trends/market", self.base_url);
         if let Some(days) = days_back {
             url = format!("{}?days_back={}", url, days);
         }
-        let response = self.client.get(&url).send().await?;
-        let trends: Vec<MarketTrend> = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let trends: Vec<MarketTrend> = self.decode(response)?;
+        Ok(trends)
+    }
+
+    /// Like [`Self::get_market_trends`], but returns a cached value if one
+    /// younger than `max_age` exists instead of refetching.
+    pub async fn get_market_trends_cached(
+        &self,
+        days_back: Option<i32>,
+        max_age: std::time::Duration,
+    ) -> Result<Vec<MarketTrend>, BackendError> {
+        let key = format!("market_trends:{:?}", days_back);
+        if let Some(cached) = self.cache.get_if_fresh(&key, max_age) {
+            return Ok(cached);
+        }
+        let trends = self.get_market_trends(days_back).await?;
+        self.cache.insert(key, &trends);
         Ok(trends)
     }
 
-    pub async fn get_forecast_models(&self) -> Result<HashMap<String, Value>, reqwest::Error> {
+    pub async fn get_forecast_models(&self) -> Result<HashMap<String, Value>, BackendError> {
         let url = format!("{}/api/v1/analytics/models", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let models: HashMap<String, Value> = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let models: HashMap<String, Value> = self.decode(response)?;
         Ok(models)
     }
 
-    pub async fn get_predictive_insights(&self) -> Result<PredictiveInsights, reqwest::Error> {
+    pub async fn get_predictive_insights(&self) -> Result<PredictiveInsights, BackendError> {
         let url = format!("{}/api/v1/analytics/insights/predictive", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let insights: PredictiveInsights = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let insights: PredictiveInsights = self.decode(response)?;
         Ok(insights)
     }
 
-    pub async fn get_stockx_listings(&self, status: Option<String>, limit: Option<i32>) -> Result<Vec<HashMap<String, Value>>, reqwest::Error> {
+    pub async fn get_stockx_listings(&self, status: Option<impl Into<String>>, limit: Option<i32>) -> Result<Vec<HashMap<String, Value>>, BackendError> {
         let mut url = format!("{}/api/v1/inventory/stockx-listings", self.base_url);
         let mut params = Vec::new();
-        
+
         if let Some(s) = status {
-            params.push(format!("status={}", s));
+            params.push(format!("status={}", s.into()));
         }
         if let Some(l) = limit {
             params.push(format!("limit={}", l));
@@ -835,8 +1202,8 @@ impl ApiClient {
             url.push_str(&params.join("&"));
         }
 
-        let response = self.client.get(&url).send().await?;
-        let data: Value = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let data: Value = self.decode(response)?;
         
         if let Some(listings) = data["data"]["listings"].as_array() {
             Ok(listings.iter().map(|v| {
@@ -853,24 +1220,20 @@ impl ApiClient {
         }
     }
 
-    pub async fn create_stockx_listing(&self, request: StockXListingRequest) -> Result<StockXListingResponse, reqwest::Error> {
+    pub async fn create_stockx_listing(&self, request: StockXListingRequest) -> Result<StockXListingResponse, BackendError> {
         let url = format!("{}/api/v1/inventory/items/{}/stockx-listing", self.base_url, request.item_id);
-        let response = self.client.post(&url)
-            .json(&serde_json::json!({
-                "listing_type": request.listing_type
-            }))
-            .send()
-            .await?;
-        let listing_response: StockXListingResponse = response.json().await?;
+        let body = serde_json::json!({ "listing_type": request.listing_type });
+        let response = self.execute_with_retry(HttpRequest::post(&url).with_json(&body)).await?;
+        let listing_response: StockXListingResponse = self.decode(response)?;
         Ok(listing_response)
     }
 
-    pub async fn get_alias_listings(&self, status: Option<String>, limit: Option<i32>) -> Result<Vec<HashMap<String, Value>>, reqwest::Error> {
+    pub async fn get_alias_listings(&self, status: Option<impl Into<String>>, limit: Option<i32>) -> Result<Vec<HashMap<String, Value>>, BackendError> {
         let mut url = format!("{}/api/v1/inventory/alias-listings", self.base_url);
         let mut params = Vec::new();
-        
+
         if let Some(s) = status {
-            params.push(format!("status={}", s));
+            params.push(format!("status={}", s.into()));
         }
         if let Some(l) = limit {
             params.push(format!("limit={}", l));
@@ -881,8 +1244,8 @@ impl ApiClient {
             url.push_str(&params.join("&"));
         }
 
-        let response = self.client.get(&url).send().await?;
-        let data: Value = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let data: Value = self.decode(response)?;
         
         if let Some(listings) = data["data"]["listings"].as_array() {
             Ok(listings.iter().map(|v| {
@@ -899,10 +1262,10 @@ impl ApiClient {
         }
     }
 
-    pub async fn sync_inventory_from_stockx(&self) -> Result<HashMap<String, Value>, reqwest::Error> {
+    pub async fn sync_inventory_from_stockx(&self) -> Result<HashMap<String, Value>, BackendError> {
         let url = format!("{}/api/v1/inventory/sync-from-stockx", self.base_url);
-        let response = self.client.post(&url).send().await?;
-        let data: Value = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url)).await?;
+        let data: Value = self.decode(response)?;
         
         let mut result = HashMap::new();
         if let Some(obj) = data.as_object() {
@@ -913,158 +1276,274 @@ impl ApiClient {
         Ok(result)
     }
 
-    pub async fn get_system_status(&self) -> Result<crate::commands::SystemStatus, reqwest::Error> {
+    pub async fn get_system_status(&self) -> Result<crate::commands::SystemStatus, BackendError> {
         let url = format!("{}/api/v1/system/status", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let status: crate::commands::SystemStatus = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let status: crate::commands::SystemStatus = self.decode(response)?;
         Ok(status)
     }
 
     // Smart Pricing API Methods
-    pub async fn optimize_inventory_pricing(&self, strategy: String, limit: i32) -> Result<SmartPricingOptimization, reqwest::Error> {
-        let url = format!("{}/api/v1/pricing/smart/optimize-inventory?strategy={}&limit={}", self.base_url, strategy, limit);
-        let response = self.client.post(&url).send().await?;
-        let optimization: SmartPricingOptimization = response.json().await?;
+    pub async fn optimize_inventory_pricing(&self, strategy: impl Into<String>, limit: i32) -> Result<SmartPricingOptimization, BackendError> {
+        let url = format!("{}/api/v1/pricing/smart/optimize-inventory?strategy={}&limit={}", self.base_url, strategy.into(), limit);
+        let response = self.execute_with_retry(HttpRequest::post(&url)).await?;
+        let optimization: SmartPricingOptimization = self.decode(response)?;
         Ok(optimization)
     }
 
-    pub async fn get_auto_repricing_status(&self) -> Result<AutoRepricingStatus, reqwest::Error> {
+    pub async fn get_auto_repricing_status(&self) -> Result<AutoRepricingStatus, BackendError> {
         let url = format!("{}/api/v1/pricing/smart/auto-repricing/status", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let status: AutoRepricingStatus = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let status: AutoRepricingStatus = self.decode(response)?;
         Ok(status)
     }
 
-    pub async fn toggle_auto_repricing(&self, enabled: bool) -> Result<HashMap<String, Value>, reqwest::Error> {
+    pub async fn toggle_auto_repricing(&self, enabled: bool) -> Result<HashMap<String, Value>, BackendError> {
         let url = format!("{}/api/v1/pricing/smart/auto-repricing/toggle", self.base_url);
         let payload = serde_json::json!({"enabled": enabled});
-        let response = self.client.post(&url).json(&payload).send().await?;
-        let result: HashMap<String, Value> = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url).with_json(&payload)).await?;
+        let result: HashMap<String, Value> = self.decode(response)?;
         Ok(result)
     }
 
-    pub async fn get_smart_market_trends(&self) -> Result<MarketTrendData, reqwest::Error> {
+    pub async fn get_smart_market_trends(&self) -> Result<MarketTrendData, BackendError> {
         let url = format!("{}/api/v1/pricing/smart/market-trends", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let trends: MarketTrendData = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let trends: MarketTrendData = self.decode(response)?;
         Ok(trends)
     }
 
+    /// Like [`Self::get_smart_market_trends`], but returns a cached value if
+    /// one younger than `max_age` exists instead of refetching.
+    pub async fn get_smart_market_trends_cached(&self, max_age: std::time::Duration) -> Result<MarketTrendData, BackendError> {
+        let key = "smart_market_trends".to_string();
+        if let Some(cached) = self.cache.get_if_fresh(&key, max_age) {
+            return Ok(cached);
+        }
+        let trends = self.get_smart_market_trends().await?;
+        self.cache.insert(key, &trends);
+        Ok(trends)
+    }
+
+    /// Forces the next `_cached` call for a given cache key to refetch, e.g.
+    /// after a listing mutation invalidates a cached market snapshot.
+    pub fn invalidate_cache(&self, key: &str) {
+        self.cache.invalidate(key);
+    }
+
+    pub fn clear_cache(&self) {
+        self.cache.clear();
+    }
+
     // Auto-Listing API Methods
-    pub async fn get_auto_listing_status(&self) -> Result<AutoListingStatus, reqwest::Error> {
+    pub async fn get_auto_listing_status(&self) -> Result<AutoListingStatus, BackendError> {
         let url = format!("{}/api/v1/pricing/auto-listing/status", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let status: AutoListingStatus = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let status: AutoListingStatus = self.decode(response)?;
         Ok(status)
     }
 
-    pub async fn execute_auto_listing(&self, max_items: i32, dry_run: bool) -> Result<AutoListingExecution, reqwest::Error> {
+    pub async fn execute_auto_listing(&self, max_items: i32, dry_run: bool) -> Result<AutoListingExecution, BackendError> {
         let url = format!("{}/api/v1/pricing/auto-listing/execute", self.base_url);
         let payload = serde_json::json!({"max_items": max_items, "dry_run": dry_run});
-        let response = self.client.post(&url).json(&payload).send().await?;
-        let execution: AutoListingExecution = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url).with_json(&payload)).await?;
+        let execution: AutoListingExecution = self.decode(response)?;
         Ok(execution)
     }
 
-    pub async fn simulate_auto_listing(&self, rule_name: Option<String>, max_items: i32) -> Result<AutoListingSimulation, reqwest::Error> {
+    pub async fn simulate_auto_listing(&self, rule_name: Option<String>, max_items: i32) -> Result<AutoListingSimulation, BackendError> {
         let mut url = format!("{}/api/v1/pricing/auto-listing/simulate?max_items={}", self.base_url, max_items);
         if let Some(rule) = rule_name {
             url = format!("{}&rule_name={}", url, rule);
         }
-        let response = self.client.post(&url).send().await?;
-        let simulation: AutoListingSimulation = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url)).await?;
+        let simulation: AutoListingSimulation = self.decode(response)?;
         Ok(simulation)
     }
 
-    pub async fn toggle_listing_rule(&self, rule_name: String, active: bool) -> Result<RuleToggleResponse, reqwest::Error> {
+    pub async fn toggle_listing_rule(&self, rule_name: String, active: bool) -> Result<RuleToggleResponse, BackendError> {
         let url = format!("{}/api/v1/pricing/auto-listing/toggle-rule", self.base_url);
         let payload = serde_json::json!({"rule_name": rule_name, "active": active});
-        let response = self.client.post(&url).json(&payload).send().await?;
-        let toggle_response: RuleToggleResponse = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url).with_json(&payload)).await?;
+        let toggle_response: RuleToggleResponse = self.decode(response)?;
         Ok(toggle_response)
     }
 
     // Dead Stock API Methods
-    pub async fn get_dead_stock_summary(&self) -> Result<DeadStockSummary, reqwest::Error> {
+    pub async fn get_dead_stock_summary(&self) -> Result<DeadStockSummary, BackendError> {
         let url = format!("{}/api/v1/pricing/dead-stock/summary", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let summary: DeadStockSummary = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let summary: DeadStockSummary = self.decode(response)?;
         Ok(summary)
     }
 
-    pub async fn analyze_dead_stock(&self, brand_filter: Option<String>, category_filter: Option<String>, min_risk_score: f64) -> Result<DeadStockAnalysis, reqwest::Error> {
+    pub async fn analyze_dead_stock(&self, brand_filter: Option<String>, category_filter: Option<String>, min_risk_score: f64) -> Result<DeadStockAnalysis, BackendError> {
         let url = format!("{}/api/v1/pricing/dead-stock/analyze", self.base_url);
         let payload = serde_json::json!({
             "brand_filter": brand_filter,
             "category_filter": category_filter,
             "min_risk_score": min_risk_score
         });
-        let response = self.client.post(&url).json(&payload).send().await?;
-        let analysis: DeadStockAnalysis = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url).with_json(&payload)).await?;
+        let analysis: DeadStockAnalysis = self.decode(response)?;
         Ok(analysis)
     }
 
-    pub async fn execute_clearance(&self, risk_levels: Vec<String>, max_items: i32, dry_run: bool) -> Result<ClearanceExecution, reqwest::Error> {
+    pub async fn execute_clearance(&self, risk_levels: Vec<impl Into<String>>, max_items: i32, dry_run: bool) -> Result<ClearanceExecution, BackendError> {
         let url = format!("{}/api/v1/pricing/dead-stock/clearance", self.base_url);
+        let risk_levels: Vec<String> = risk_levels.into_iter().map(Into::into).collect();
         let payload = serde_json::json!({
             "risk_levels": risk_levels,
             "max_items": max_items,
             "dry_run": dry_run
         });
-        let response = self.client.post(&url).json(&payload).send().await?;
-        let execution: ClearanceExecution = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::post(&url).with_json(&payload)).await?;
+        let execution: ClearanceExecution = self.decode(response)?;
         Ok(execution)
     }
 
-    pub async fn get_risk_level_definitions(&self) -> Result<RiskLevelDefinitions, reqwest::Error> {
+    pub async fn get_risk_level_definitions(&self) -> Result<RiskLevelDefinitions, BackendError> {
         let url = format!("{}/api/v1/pricing/dead-stock/risk-levels", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let definitions: RiskLevelDefinitions = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let definitions: RiskLevelDefinitions = self.decode(response)?;
         Ok(definitions)
     }
 
-    pub async fn get_dead_stock_trends(&self) -> Result<DeadStockTrends, reqwest::Error> {
+    pub async fn get_dead_stock_trends(&self) -> Result<DeadStockTrends, BackendError> {
         let url = format!("{}/api/v1/pricing/dead-stock/trends", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let trends: DeadStockTrends = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let trends: DeadStockTrends = self.decode(response)?;
         Ok(trends)
     }
 
     // Predictive Insights API Methods
-    pub async fn get_predictive_insights(&self, insight_types: Option<String>, days_ahead: i32, limit: i32) -> Result<Vec<PredictiveInsight>, reqwest::Error> {
+    pub async fn get_predictive_insights_filtered(&self, insight_types: Option<String>, days_ahead: i32, limit: i32) -> Result<Vec<PredictiveInsight>, BackendError> {
         let mut url = format!("{}/api/v1/pricing/predictive/insights?days_ahead={}&limit={}", self.base_url, days_ahead, limit);
         if let Some(types) = insight_types {
             url = format!("{}&insight_types={}", url, types);
         }
-        let response = self.client.get(&url).send().await?;
-        let insights: Vec<PredictiveInsight> = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let insights: Vec<PredictiveInsight> = self.decode(response)?;
         Ok(insights)
     }
 
-    pub async fn get_inventory_forecasts(&self, product_ids: Option<String>, horizon_days: i32) -> Result<Vec<InventoryForecast>, reqwest::Error> {
+    /// Batched variant of [`ApiClient::get_predictive_insights_filtered`] above,
+    /// chunking `insight_types` the same way [`ApiClient::get_inventory_forecasts_batched`] chunks product ids.
+    pub async fn get_predictive_insights_batched(
+        &self,
+        insight_types: Vec<String>,
+        days_ahead: i32,
+        limit: i32,
+    ) -> BatchResult<PredictiveInsight> {
+        let chunks = chunk_ids(&insight_types, MAX_BATCH_SIZE);
+
+        let mut outcomes: Vec<(usize, Vec<String>, Result<Vec<PredictiveInsight>, BackendError>)> = stream::iter(
+            chunks.into_iter().enumerate().map(|(index, chunk)| {
+                let client = self.clone();
+                async move {
+                    let result = client.get_predictive_insights_filtered(Some(chunk.join(",")), days_ahead, limit).await;
+                    (index, chunk, result)
+                }
+            }),
+        )
+        .buffer_unordered(PARALLEL_REQUESTS)
+        .collect()
+        .await;
+        outcomes.sort_by_key(|(index, _, _)| *index);
+
+        let mut items = Vec::new();
+        let mut failures = Vec::new();
+        for (_, ids, result) in outcomes {
+            match result {
+                Ok(insights) => items.extend(insights),
+                Err(error) => failures.push(BatchFailure { ids, error }),
+            }
+        }
+        BatchResult { items, failures }
+    }
+
+    pub async fn get_inventory_forecasts(&self, product_ids: Option<String>, horizon_days: i32) -> Result<Vec<InventoryForecast>, BackendError> {
         let mut url = format!("{}/api/v1/pricing/predictive/forecasts?horizon_days={}", self.base_url, horizon_days);
         if let Some(ids) = product_ids {
             url = format!("{}&product_ids={}", url, ids);
         }
-        let response = self.client.get(&url).send().await?;
-        let forecasts: Vec<InventoryForecast> = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let forecasts: Vec<InventoryForecast> = self.decode(response)?;
         Ok(forecasts)
     }
 
-    pub async fn get_restock_recommendations(&self, investment_budget: Option<f64>, min_roi: f64, max_products: i32) -> Result<Vec<RestockRecommendation>, reqwest::Error> {
+    /// Batched variant of [`ApiClient::get_inventory_forecasts`] for callers
+    /// with thousands of product ids: splits `product_ids` into chunks of
+    /// [`MAX_BATCH_SIZE`], fires up to [`PARALLEL_REQUESTS`] chunk requests
+    /// concurrently, and concatenates the results in input order. A chunk
+    /// that errors is recorded as a [`BatchFailure`] instead of failing the
+    /// whole batch.
+    pub async fn get_inventory_forecasts_batched(
+        &self,
+        product_ids: Vec<String>,
+        horizon_days: i32,
+    ) -> BatchResult<InventoryForecast> {
+        let chunks = chunk_ids(&product_ids, MAX_BATCH_SIZE);
+
+        let mut outcomes: Vec<(usize, Vec<String>, Result<Vec<InventoryForecast>, BackendError>)> = stream::iter(
+            chunks.into_iter().enumerate().map(|(index, chunk)| {
+                let client = self.clone();
+                async move {
+                    let result = client.get_inventory_forecasts(Some(chunk.join(",")), horizon_days).await;
+                    (index, chunk, result)
+                }
+            }),
+        )
+        .buffer_unordered(PARALLEL_REQUESTS)
+        .collect()
+        .await;
+        outcomes.sort_by_key(|(index, _, _)| *index);
+
+        let mut items = Vec::new();
+        let mut failures = Vec::new();
+        for (_, ids, result) in outcomes {
+            match result {
+                Ok(forecasts) => items.extend(forecasts),
+                Err(error) => failures.push(BatchFailure { ids, error }),
+            }
+        }
+        BatchResult { items, failures }
+    }
+
+    // `get_restock_recommendations` takes no product-id-keyed parameter (only
+    // a budget/ROI/count filter), so there's nothing here to chunk.
+    pub async fn get_restock_recommendations(&self, investment_budget: Option<f64>, min_roi: f64, max_products: i32) -> Result<Vec<RestockRecommendation>, BackendError> {
         let mut url = format!("{}/api/v1/pricing/predictive/restock-recommendations?min_roi={}&max_products={}", self.base_url, min_roi, max_products);
         if let Some(budget) = investment_budget {
             url = format!("{}&investment_budget={}", url, budget);
         }
-        let response = self.client.get(&url).send().await?;
-        let recommendations: Vec<RestockRecommendation> = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let recommendations: Vec<RestockRecommendation> = self.decode(response)?;
         Ok(recommendations)
     }
 
-    pub async fn get_predictive_insights_summary(&self) -> Result<PredictiveInsightsSummary, reqwest::Error> {
+    pub async fn get_predictive_insights_summary(&self) -> Result<PredictiveInsightsSummary, BackendError> {
         let url = format!("{}/api/v1/pricing/predictive/summary", self.base_url);
-        let response = self.client.get(&url).send().await?;
-        let summary: PredictiveInsightsSummary = response.json().await?;
+        let response = self.execute_with_retry(HttpRequest::get(&url)).await?;
+        let summary: PredictiveInsightsSummary = self.decode(response)?;
         Ok(summary)
     }
+
+}
+
+impl ApiClient<ReqwestExecutor> {
+    /// Fetches the dead-stock and dashboard summaries and evaluates `rules`
+    /// against them, returning the alerts that fired (outside their cooldown
+    /// window) and pushing them to every sink registered via
+    /// [`ApiClient::register_alert_sink`].
+    pub async fn evaluate_alerts(&self, rules: &[AlertRule]) -> Result<Vec<Alert>, BackendError> {
+        self.alerts.evaluate(self, rules).await
+    }
+
+    /// Subscribes to every alert fired by future [`ApiClient::evaluate_alerts`]
+    /// calls on this client (and any clone of it, since they share the same
+    /// underlying engine).
+    pub fn register_alert_sink(&self) -> tokio::sync::mpsc::UnboundedReceiver<Alert> {
+        self.alerts.register_sink()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How the client authenticates against the backend. `ApiKey`/`Bearer` are
+/// used as-is on every request; `UsernamePassword` is exchanged for a token
+/// via [`crate::api::ApiClient::authenticate`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind")]
+pub enum Credentials {
+    ApiKey(String),
+    Bearer(String),
+    UsernamePassword { username: String, password: String },
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LoginResponse {
+    pub token: String,
+    pub expires_in_seconds: i64,
+}
+
+/// A cached login session. `expires_at` is checked with a safety margin so a
+/// request doesn't race a token that's about to expire mid-flight.
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+const EXPIRY_SAFETY_MARGIN: Duration = Duration::seconds(30);
+
+impl Session {
+    pub fn from_login(response: LoginResponse) -> Self {
+        Self {
+            token: response.token,
+            expires_at: Utc::now() + Duration::seconds(response.expires_in_seconds),
+        }
+    }
+
+    pub fn needs_refresh(&self) -> bool {
+        Utc::now() + EXPIRY_SAFETY_MARGIN >= self.expires_at
+    }
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use crate::api::ApiClient;
+use crate::auth::Credentials;
+use crate::rate_limit::{RateLimiter, RetryPolicy};
+use crate::transport::ReqwestExecutor;
+
+const PRODUCTION_URL: &str = "https://api.soleflip.app";
+const STAGING_URL: &str = "https://staging-api.soleflip.app";
+
+/// Builds an [`ApiClient`] with a preset endpoint, request/connect timeouts,
+/// default credentials, and retry/backoff settings, instead of threading all
+/// of that through `ApiClient::new`/`with_credentials` by hand. Mirrors the
+/// `live()`/`demo()`-style presets seen in brokerage SDKs: `production()` and
+/// `staging()` point at known hosts, `custom()` covers a self-hosted backend.
+pub struct ClientBuilder {
+    base_url: String,
+    request_timeout: Duration,
+    connect_timeout: Duration,
+    credentials: Option<Credentials>,
+    rate_limiter: RateLimiter,
+    retry_policy: RetryPolicy,
+}
+
+impl ClientBuilder {
+    pub fn production() -> Self {
+        Self::custom(PRODUCTION_URL)
+    }
+
+    pub fn staging() -> Self {
+        Self::custom(STAGING_URL)
+    }
+
+    /// A self-hosted or locally-run backend at an arbitrary URL.
+    pub fn custom(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            request_timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+            credentials: None,
+            rate_limiter: RateLimiter::default(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Authenticates every request with an API key, sent as a bearer token.
+    pub fn api_key(self, key: impl Into<String>) -> Self {
+        self.credentials(Credentials::ApiKey(key.into()))
+    }
+
+    /// Authenticates every request with a pre-issued bearer token.
+    pub fn bearer_token(self, token: impl Into<String>) -> Self {
+        self.credentials(Credentials::Bearer(token.into()))
+    }
+
+    pub fn credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn build(self) -> ApiClient<ReqwestExecutor> {
+        let reqwest_client = reqwest::Client::builder()
+            .timeout(self.request_timeout)
+            .connect_timeout(self.connect_timeout)
+            .build()
+            .expect("reqwest client configuration must be valid");
+
+        let client = ApiClient::with_executor(
+            self.base_url,
+            ReqwestExecutor::new(reqwest_client),
+            self.rate_limiter,
+            self.retry_policy,
+        );
+
+        match self.credentials {
+            Some(credentials) => client.with_credentials(credentials),
+            None => client,
+        }
+    }
+}
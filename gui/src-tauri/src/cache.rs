@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+/// In-memory cache for read-heavy, slow-changing endpoints (market trends,
+/// pricing insights) keyed by endpoint name + params, so repeated polling
+/// doesn't refetch on every call. Shared across clones of an `ApiClient` via
+/// `Arc`, mirroring how [`crate::auth::Session`] is shared.
+#[derive(Default)]
+pub struct ResponseCache {
+    entries: RwLock<HashMap<String, (Instant, Value)>>,
+}
+
+impl ResponseCache {
+    /// Returns the cached value for `key` if it was stored less than
+    /// `max_age` ago, deserialized as `T`. A stale or missing entry (or one
+    /// that no longer matches `T`'s shape) is treated as a cache miss.
+    pub fn get_if_fresh<T: serde::de::DeserializeOwned>(&self, key: &str, max_age: Duration) -> Option<T> {
+        let entries = self.entries.read().unwrap();
+        let (fetched_at, value) = entries.get(key)?;
+        if fetched_at.elapsed() > max_age {
+            return None;
+        }
+        serde_json::from_value(value.clone()).ok()
+    }
+
+    pub fn insert<T: serde::Serialize>(&self, key: impl Into<String>, value: &T) {
+        if let Ok(value) = serde_json::to_value(value) {
+            self.entries.write().unwrap().insert(key.into(), (Instant::now(), value));
+        }
+    }
+
+    /// Forces the next `_cached` call for `key` to refetch, e.g. after a
+    /// listing mutation invalidates a cached market snapshot.
+    pub fn invalidate(&self, key: &str) {
+        self.entries.write().unwrap().remove(key);
+    }
+
+    pub fn clear(&self) {
+        self.entries.write().unwrap().clear();
+    }
+}
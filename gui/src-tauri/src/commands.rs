@@ -1,4 +1,9 @@
-use crate::api::{ApiClient, HealthStatus, InventoryItem, ProductStats, ImportRequest, ImportResponse, ImportStatus, DashboardMetrics, EnrichmentStatusResponse, EnrichmentResponse, PricingRequest, PricingRecommendation, MarketAnalysis, PricingInsights, ForecastRequest, ForecastAnalysis, MarketTrend, PredictiveInsights, SmartPricingOptimization, AutoRepricingStatus, MarketTrendData, PredictiveInsight, InventoryForecast, RestockRecommendation, PredictiveInsightsSummary};
+use crate::api::{HealthStatus, InventoryItem, ProductStats, ImportRequest, ImportResponse, ImportStatus, DashboardMetrics, EnrichmentStatusResponse, EnrichmentResponse, PricingRequest, PricingRecommendation, MarketAnalysis, PricingInsights, ForecastRequest, ForecastAnalysis, MarketTrend, PredictiveInsights, SmartPricingOptimization, AutoRepricingStatus, MarketTrendData, PredictiveInsight, InventoryForecast, RestockRecommendation, PredictiveInsightsSummary};
+use crate::config::{AppConfig, AppState};
+use crate::db::{Cached, CacheDb};
+use crate::error::BackendError;
+use crate::search::{SearchHit, SearchIndex};
+use crate::stream::{StreamClient, StreamTopic};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -27,30 +32,54 @@ pub struct StockXListingResponse {
 }
 
 #[tauri::command]
-pub async fn health_check() -> Result<HealthStatus, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.health_check().await {
-        Ok(health) => Ok(health),
-        Err(e) => Err(format!("Health check failed: {}", e)),
-    }
+pub async fn health_check(state: tauri::State<'_, AppState>) -> Result<HealthStatus, BackendError> {
+    let client = state.client();
+    Ok(client.health_check().await?)
 }
 
 #[tauri::command]
-pub async fn get_inventory_items(limit: Option<i32>) -> Result<Vec<InventoryItem>, String> {
+pub async fn get_inventory_items(limit: Option<i32>, state: tauri::State<'_, AppState>, cache: tauri::State<'_, CacheDb>, search_index: tauri::State<'_, SearchIndex>) -> Result<Cached<Vec<InventoryItem>>, BackendError> {
     eprintln!("🔍 Tauri command get_inventory_items called with limit: {:?}", limit);
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_inventory_items(limit).await {
+    let client = state.client();
+
+    let result = match client.get_inventory_items(limit).await {
         Ok(items) => {
             eprintln!("✅ Successfully loaded {} inventory items", items.len());
-            Ok(items)
-        },
+            cache.upsert_inventory_items(&items).await?;
+            Cached { data: items, stale_since: None }
+        }
         Err(e) => {
-            eprintln!("❌ Failed to fetch inventory: {}", e);
-            Err(format!("Failed to fetch inventory: {}", e))
+            eprintln!("❌ Failed to fetch inventory, falling back to offline cache: {}", e);
+            cache.get_cached_inventory_items(limit).await?
         }
-    }
+    };
+
+    search_index.rebuild(&result.data);
+    Ok(result)
+}
+
+#[tauri::command]
+pub async fn get_inventory_delta(
+    last_knowledge: Option<u64>,
+    state: tauri::State<'_, AppState>,
+    cache: tauri::State<'_, CacheDb>,
+    search_index: tauri::State<'_, SearchIndex>,
+) -> Result<crate::api::InventoryDelta, BackendError> {
+    let client = state.client();
+    let delta = client.get_inventory_delta(last_knowledge).await?;
+
+    cache.upsert_inventory_items(&delta.upserts).await?;
+    cache.delete_inventory_items(&delta.deleted_ids).await?;
+
+    let cached = cache.get_cached_inventory_items(None).await?;
+    search_index.rebuild(&cached.data);
+
+    Ok(delta)
+}
+
+#[tauri::command]
+pub async fn search_inventory(query: String, limit: Option<usize>, search_index: tauri::State<'_, SearchIndex>) -> Result<Vec<SearchHit>, BackendError> {
+    Ok(search_index.search(&query, limit.unwrap_or(25)))
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -61,111 +90,87 @@ pub struct HttpRequest {
 }
 
 #[tauri::command]
-pub async fn http_request(method: String, url: String, body: Option<Value>) -> Result<Value, String> {
+pub async fn http_request(method: String, url: String, body: Option<Value>, state: tauri::State<'_, AppState>) -> Result<Value, BackendError> {
     let client = reqwest::Client::new();
     let full_url = if url.starts_with("http") {
         url
     } else {
-        format!("http://localhost:8000{}", url)
+        format!("{}{}", state.config().api_url, url)
     };
-    
+
     let mut request = match method.to_uppercase().as_str() {
         "GET" => client.get(&full_url),
         "POST" => client.post(&full_url),
         "PUT" => client.put(&full_url),
         "DELETE" => client.delete(&full_url),
-        _ => return Err("Unsupported HTTP method".to_string()),
+        _ => return Err(BackendError::Config(format!("unsupported HTTP method: {}", method))),
     };
-    
+
     if let Some(body_data) = body {
         request = request.json(&body_data);
     }
-    
-    match request.send().await {
-        Ok(response) => {
-            match response.json::<Value>().await {
-                Ok(json) => Ok(json),
-                Err(e) => Err(format!("Failed to parse response: {}", e)),
-            }
-        }
-        Err(e) => Err(format!("Request failed: {}", e)),
-    }
+
+    let response = request.send().await?;
+    let json: Value = response.json().await?;
+    Ok(json)
 }
 
 #[tauri::command]
-pub async fn get_product_stats() -> Result<ProductStats, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_product_stats().await {
-        Ok(stats) => Ok(stats),
-        Err(e) => Err(format!("Failed to fetch product stats: {}", e)),
-    }
+pub async fn get_product_stats(state: tauri::State<'_, AppState>) -> Result<ProductStats, BackendError> {
+    let client = state.client();
+    Ok(client.get_product_stats().await?)
 }
 
 #[tauri::command]
-pub async fn import_stockx_data(from_date: String, to_date: String) -> Result<ImportResponse, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
+pub async fn import_stockx_data(from_date: String, to_date: String, state: tauri::State<'_, AppState>) -> Result<ImportResponse, BackendError> {
+    let client = state.client();
     let request = ImportRequest { from_date, to_date };
-    
-    match client.import_stockx_data(request).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(format!("Failed to start import: {}", e)),
-    }
+    Ok(client.import_stockx_data(request).await?)
 }
 
 #[tauri::command]
-pub async fn get_import_status(batch_id: String) -> Result<ImportStatus, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    let uuid = Uuid::parse_str(&batch_id)
-        .map_err(|e| format!("Invalid batch ID: {}", e))?;
-    
-    match client.get_import_status(uuid).await {
-        Ok(status) => Ok(status),
-        Err(e) => Err(format!("Failed to fetch import status: {}", e)),
-    }
+pub async fn get_import_status(batch_id: String, state: tauri::State<'_, AppState>) -> Result<ImportStatus, BackendError> {
+    let client = state.client();
+    let uuid = Uuid::parse_str(&batch_id)?;
+    Ok(client.get_import_status(uuid).await?)
 }
 
 #[tauri::command]
-pub async fn get_dashboard_metrics() -> Result<DashboardMetrics, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
+pub async fn get_dashboard_metrics(state: tauri::State<'_, AppState>, cache: tauri::State<'_, CacheDb>) -> Result<Cached<DashboardMetrics>, BackendError> {
+    let client = state.client();
+
     match client.get_dashboard_metrics().await {
-        Ok(metrics) => Ok(metrics),
-        Err(e) => Err(format!("Failed to fetch dashboard metrics: {}", e)),
+        Ok(metrics) => {
+            cache.upsert_dashboard_metrics(&serde_json::to_value(&metrics)?).await?;
+            Ok(Cached { data: metrics, stale_since: None })
+        }
+        Err(_) => {
+            let cached = cache.get_cached_dashboard_metrics().await?;
+            Ok(Cached {
+                data: serde_json::from_value(cached.data)?,
+                stale_since: cached.stale_since,
+            })
+        }
     }
 }
 
 #[tauri::command]
-pub async fn run_database_query(query: String) -> Result<Vec<HashMap<String, Value>>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    // Security check - only allow SELECT queries
-    let query_trimmed = query.trim().to_lowercase();
-    if !query_trimmed.starts_with("select") {
-        return Err("Only SELECT queries are allowed for security reasons".to_string());
-    }
-    
-    match client.run_database_query(query).await {
-        Ok(results) => Ok(results),
-        Err(e) => Err(format!("Query failed: {}", e)),
-    }
+pub async fn run_database_query(query: String, state: tauri::State<'_, AppState>) -> Result<Vec<HashMap<String, Value>>, BackendError> {
+    let client = state.client();
+    let safe_query = crate::query_guard::validate_read_only(&query)?;
+    Ok(client.run_database_query(safe_query).await?)
 }
 
 #[tauri::command]
-pub async fn export_data_csv(table: String, filters: Option<HashMap<String, String>>) -> Result<String, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.export_data_csv(table, filters).await {
-        Ok(csv_data) => Ok(csv_data),
-        Err(e) => Err(format!("Export failed: {}", e)),
-    }
+pub async fn export_data_csv(table: String, filters: Option<HashMap<String, String>>, state: tauri::State<'_, AppState>) -> Result<String, BackendError> {
+    let client = state.client();
+    Ok(client.export_data_csv(table, filters).await?)
 }
 
 #[tauri::command]
-pub async fn get_system_status() -> Result<SystemStatus, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
+pub async fn get_system_status(state: tauri::State<'_, AppState>) -> Result<SystemStatus, BackendError> {
+    let client = state.client();
+
     match client.health_check().await {
         Ok(health) => {
             // Check if database component is healthy
@@ -174,7 +179,7 @@ pub async fn get_system_status() -> Result<SystemStatus, String> {
                 .and_then(|db| db.get("status"))
                 .and_then(|status| status.as_str())
                 .map_or(false, |status| status == "healthy");
-            
+
             Ok(SystemStatus {
                 api_connected: true,
                 database_healthy,
@@ -182,7 +187,7 @@ pub async fn get_system_status() -> Result<SystemStatus, String> {
                 version: health.version,
                 environment: health.environment,
             })
-        },
+        }
         Err(_e) => Ok(SystemStatus {
             api_connected: false,
             database_healthy: false,
@@ -194,297 +199,322 @@ pub async fn get_system_status() -> Result<SystemStatus, String> {
 }
 
 #[tauri::command]
-pub async fn get_enrichment_status() -> Result<EnrichmentStatusResponse, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_enrichment_status().await {
-        Ok(status) => Ok(status),
-        Err(e) => Err(format!("Failed to fetch enrichment status: {}", e)),
-    }
+pub async fn get_enrichment_status(state: tauri::State<'_, AppState>) -> Result<EnrichmentStatusResponse, BackendError> {
+    let client = state.client();
+    Ok(client.get_enrichment_status().await?)
 }
 
 #[tauri::command]
-pub async fn start_product_enrichment(product_ids: Option<Vec<String>>) -> Result<EnrichmentResponse, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.start_product_enrichment(product_ids).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(format!("Failed to start product enrichment: {}", e)),
-    }
+pub async fn start_product_enrichment(product_ids: Option<Vec<String>>, state: tauri::State<'_, AppState>) -> Result<EnrichmentResponse, BackendError> {
+    let client = state.client();
+    Ok(client.start_product_enrichment(product_ids).await?)
 }
 
 // Pricing Commands
 #[tauri::command]
-pub async fn get_pricing_recommendation(request: PricingRequest) -> Result<PricingRecommendation, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_pricing_recommendation(request).await {
-        Ok(recommendation) => Ok(recommendation),
-        Err(e) => Err(format!("Failed to get pricing recommendation: {}", e)),
-    }
+pub async fn get_pricing_recommendation(request: PricingRequest, state: tauri::State<'_, AppState>) -> Result<PricingRecommendation, BackendError> {
+    let client = state.client();
+    Ok(client.get_pricing_recommendation(request).await?)
 }
 
 #[tauri::command]
-pub async fn get_market_analysis(product_id: String) -> Result<MarketAnalysis, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_market_analysis(product_id).await {
-        Ok(analysis) => Ok(analysis),
-        Err(e) => Err(format!("Failed to get market analysis: {}", e)),
-    }
+pub async fn get_market_analysis(product_id: String, state: tauri::State<'_, AppState>) -> Result<MarketAnalysis, BackendError> {
+    let client = state.client();
+    Ok(client.get_market_analysis(product_id).await?)
 }
 
 #[tauri::command]
-pub async fn get_pricing_insights() -> Result<PricingInsights, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_pricing_insights().await {
-        Ok(insights) => Ok(insights),
-        Err(e) => Err(format!("Failed to get pricing insights: {}", e)),
-    }
+pub async fn get_market_analysis_at(
+    product_id: String,
+    at: crate::api::RequestTime,
+    state: tauri::State<'_, AppState>,
+) -> Result<crate::api::MarketAnalysisAt, BackendError> {
+    let client = state.client();
+    Ok(client.get_market_analysis_at(product_id, at).await?)
 }
 
 #[tauri::command]
-pub async fn get_pricing_strategies() -> Result<HashMap<String, Value>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_pricing_strategies().await {
-        Ok(strategies) => Ok(strategies),
-        Err(e) => Err(format!("Failed to get pricing strategies: {}", e)),
-    }
+pub async fn get_pricing_insights(state: tauri::State<'_, AppState>) -> Result<PricingInsights, BackendError> {
+    let client = state.client();
+    Ok(client.get_pricing_insights().await?)
+}
+
+#[tauri::command]
+pub async fn get_pricing_strategies(state: tauri::State<'_, AppState>) -> Result<HashMap<String, Value>, BackendError> {
+    let client = state.client();
+    Ok(client.get_pricing_strategies().await?)
 }
 
 // Analytics/Forecast Commands
 #[tauri::command]
-pub async fn generate_sales_forecast(request: ForecastRequest) -> Result<ForecastAnalysis, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.generate_sales_forecast(request).await {
-        Ok(forecast) => Ok(forecast),
-        Err(e) => Err(format!("Failed to generate sales forecast: {}", e)),
-    }
+pub async fn generate_sales_forecast(request: ForecastRequest, state: tauri::State<'_, AppState>) -> Result<ForecastAnalysis, BackendError> {
+    let client = state.client();
+    Ok(client.generate_sales_forecast(request).await?)
 }
 
 #[tauri::command]
-pub async fn get_market_trends(days_back: Option<i32>) -> Result<Vec<MarketTrend>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_market_trends(days_back).await {
-        Ok(trends) => Ok(trends),
-        Err(e) => Err(format!("Failed to get market trends: {}", e)),
-    }
+pub async fn get_market_trends(days_back: Option<i32>, state: tauri::State<'_, AppState>) -> Result<Vec<MarketTrend>, BackendError> {
+    let client = state.client();
+    Ok(client.get_market_trends(days_back).await?)
 }
 
 #[tauri::command]
-pub async fn get_forecast_models() -> Result<HashMap<String, Value>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_forecast_models().await {
-        Ok(models) => Ok(models),
-        Err(e) => Err(format!("Failed to get forecast models: {}", e)),
-    }
+pub async fn get_forecast_models(state: tauri::State<'_, AppState>) -> Result<HashMap<String, Value>, BackendError> {
+    let client = state.client();
+    Ok(client.get_forecast_models().await?)
 }
 
 
 #[tauri::command]
-pub async fn create_stockx_listing(item_id: String, listing_type: String) -> Result<StockXListingResponse, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
+pub async fn create_stockx_listing(item_id: String, listing_type: String, state: tauri::State<'_, AppState>) -> Result<StockXListingResponse, BackendError> {
+    let client = state.client();
     let request = StockXListingRequest { item_id, listing_type };
-    
-    match client.create_stockx_listing(request).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(format!("Failed to create StockX listing: {}", e)),
-    }
+    Ok(client.create_stockx_listing(request).await?)
 }
 
 #[tauri::command]
-pub async fn get_stockx_listings(status: Option<String>, limit: Option<i32>) -> Result<Vec<HashMap<String, Value>>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
+pub async fn get_stockx_listings(status: Option<String>, limit: Option<i32>, state: tauri::State<'_, AppState>, cache: tauri::State<'_, CacheDb>) -> Result<Cached<Vec<HashMap<String, Value>>>, BackendError> {
+    let client = state.client();
+
     match client.get_stockx_listings(status, limit).await {
-        Ok(listings) => Ok(listings),
-        Err(e) => Err(format!("Failed to get StockX listings: {}", e)),
+        Ok(listings) => {
+            cache.upsert_stockx_listings(&listings).await?;
+            Ok(Cached { data: listings, stale_since: None })
+        }
+        Err(_) => cache.get_cached_stockx_listings().await,
     }
 }
 
 #[tauri::command]
-pub async fn get_alias_listings(status: Option<String>, limit: Option<i32>) -> Result<Vec<HashMap<String, Value>>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_alias_listings(status, limit).await {
-        Ok(listings) => Ok(listings),
-        Err(e) => Err(format!("Failed to get Alias listings: {}", e)),
-    }
+pub async fn get_alias_listings(status: Option<String>, limit: Option<i32>, state: tauri::State<'_, AppState>) -> Result<Vec<HashMap<String, Value>>, BackendError> {
+    let client = state.client();
+    Ok(client.get_alias_listings(status, limit).await?)
 }
 
+/// Fetches StockX or Alias listings and renders them as a CSV string, so
+/// resellers can save the result straight to a ledger without hand-rolling
+/// serialization. `platform` is `"stockx"` or `"alias"`.
 #[tauri::command]
-pub async fn sync_inventory_from_stockx() -> Result<HashMap<String, Value>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.sync_inventory_from_stockx().await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(format!("Failed to sync inventory from StockX: {}", e)),
-    }
+pub async fn export_listings_csv(platform: String, status: Option<String>, limit: Option<i32>, state: tauri::State<'_, AppState>) -> Result<String, BackendError> {
+    let client = state.client();
+    let listings = match platform.as_str() {
+        "stockx" => client.get_stockx_listings(status, limit).await?,
+        "alias" => client.get_alias_listings(status, limit).await?,
+        other => return Err(BackendError::Config(format!("unknown listing platform: {}", other))),
+    };
+    Ok(crate::csv_export::rows_to_csv(&listings)?)
+}
+
+#[tauri::command]
+pub async fn sync_inventory_from_stockx(state: tauri::State<'_, AppState>) -> Result<HashMap<String, Value>, BackendError> {
+    let client = state.client();
+    Ok(client.sync_inventory_from_stockx().await?)
 }
 
 // Smart Pricing Commands
 #[tauri::command]
-pub async fn optimize_inventory_pricing(strategy: String, limit: i32) -> Result<SmartPricingOptimization, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.optimize_inventory_pricing(strategy, limit).await {
-        Ok(optimization) => Ok(optimization),
-        Err(e) => Err(format!("Failed to optimize inventory pricing: {}", e)),
-    }
+pub async fn optimize_inventory_pricing(strategy: String, limit: i32, state: tauri::State<'_, AppState>) -> Result<SmartPricingOptimization, BackendError> {
+    let client = state.client();
+    Ok(client.optimize_inventory_pricing(strategy, limit).await?)
 }
 
 #[tauri::command]
-pub async fn get_auto_repricing_status() -> Result<AutoRepricingStatus, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_auto_repricing_status().await {
-        Ok(status) => Ok(status),
-        Err(e) => Err(format!("Failed to get auto-repricing status: {}", e)),
-    }
+pub async fn get_auto_repricing_status(state: tauri::State<'_, AppState>) -> Result<AutoRepricingStatus, BackendError> {
+    let client = state.client();
+    Ok(client.get_auto_repricing_status().await?)
 }
 
 #[tauri::command]
-pub async fn toggle_auto_repricing(enabled: bool) -> Result<HashMap<String, Value>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.toggle_auto_repricing(enabled).await {
-        Ok(result) => Ok(result),
-        Err(e) => Err(format!("Failed to toggle auto-repricing: {}", e)),
-    }
+pub async fn toggle_auto_repricing(enabled: bool, state: tauri::State<'_, AppState>) -> Result<HashMap<String, Value>, BackendError> {
+    let client = state.client();
+    Ok(client.toggle_auto_repricing(enabled).await?)
 }
 
 #[tauri::command]
-pub async fn get_smart_market_trends() -> Result<MarketTrendData, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    
-    match client.get_smart_market_trends().await {
-        Ok(trends) => Ok(trends),
-        Err(e) => Err(format!("Failed to get smart market trends: {}", e)),
-    }
+pub async fn get_smart_market_trends(state: tauri::State<'_, AppState>) -> Result<MarketTrendData, BackendError> {
+    let client = state.client();
+    Ok(client.get_smart_market_trends().await?)
 }
 
 // Auto-Listing Commands
 #[tauri::command]
-pub async fn get_auto_listing_status() -> Result<crate::api::AutoListingStatus, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.get_auto_listing_status().await {
-        Ok(status) => Ok(status),
-        Err(e) => Err(format!("Failed to get auto-listing status: {}", e)),
-    }
+pub async fn get_auto_listing_status(state: tauri::State<'_, AppState>) -> Result<crate::api::AutoListingStatus, BackendError> {
+    let client = state.client();
+    Ok(client.get_auto_listing_status().await?)
 }
 
 #[tauri::command]
-pub async fn execute_auto_listing(max_items: i32, dry_run: bool) -> Result<crate::api::AutoListingExecution, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.execute_auto_listing(max_items, dry_run).await {
-        Ok(execution) => Ok(execution),
-        Err(e) => Err(format!("Failed to execute auto-listing: {}", e)),
-    }
+pub async fn execute_auto_listing(max_items: i32, dry_run: bool, state: tauri::State<'_, AppState>) -> Result<crate::api::AutoListingExecution, BackendError> {
+    let client = state.client();
+    Ok(client.execute_auto_listing(max_items, dry_run).await?)
 }
 
 #[tauri::command]
-pub async fn simulate_auto_listing(rule_name: Option<String>, max_items: i32) -> Result<crate::api::AutoListingSimulation, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.simulate_auto_listing(rule_name, max_items).await {
-        Ok(simulation) => Ok(simulation),
-        Err(e) => Err(format!("Failed to simulate auto-listing: {}", e)),
-    }
+pub async fn simulate_auto_listing(rule_name: Option<String>, max_items: i32, state: tauri::State<'_, AppState>) -> Result<crate::api::AutoListingSimulation, BackendError> {
+    let client = state.client();
+    Ok(client.simulate_auto_listing(rule_name, max_items).await?)
 }
 
 #[tauri::command]
-pub async fn toggle_listing_rule(rule_name: String, active: bool) -> Result<crate::api::RuleToggleResponse, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.toggle_listing_rule(rule_name, active).await {
-        Ok(response) => Ok(response),
-        Err(e) => Err(format!("Failed to toggle listing rule: {}", e)),
-    }
+pub async fn toggle_listing_rule(rule_name: String, active: bool, state: tauri::State<'_, AppState>) -> Result<crate::api::RuleToggleResponse, BackendError> {
+    let client = state.client();
+    Ok(client.toggle_listing_rule(rule_name, active).await?)
 }
 
 // Dead Stock Commands
 #[tauri::command]
-pub async fn get_dead_stock_summary() -> Result<crate::api::DeadStockSummary, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.get_dead_stock_summary().await {
-        Ok(summary) => Ok(summary),
-        Err(e) => Err(format!("Failed to get dead stock summary: {}", e)),
-    }
+pub async fn get_dead_stock_summary(state: tauri::State<'_, AppState>) -> Result<crate::api::DeadStockSummary, BackendError> {
+    let client = state.client();
+    Ok(client.get_dead_stock_summary().await?)
 }
 
 #[tauri::command]
-pub async fn analyze_dead_stock(brand_filter: Option<String>, category_filter: Option<String>, min_risk_score: f64) -> Result<crate::api::DeadStockAnalysis, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.analyze_dead_stock(brand_filter, category_filter, min_risk_score).await {
-        Ok(analysis) => Ok(analysis),
-        Err(e) => Err(format!("Failed to analyze dead stock: {}", e)),
-    }
+pub async fn analyze_dead_stock(brand_filter: Option<String>, category_filter: Option<String>, min_risk_score: f64, state: tauri::State<'_, AppState>) -> Result<crate::api::DeadStockAnalysis, BackendError> {
+    let client = state.client();
+    Ok(client.analyze_dead_stock(brand_filter, category_filter, min_risk_score).await?)
 }
 
 #[tauri::command]
-pub async fn execute_clearance(risk_levels: Vec<String>, max_items: i32, dry_run: bool) -> Result<crate::api::ClearanceExecution, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.execute_clearance(risk_levels, max_items, dry_run).await {
-        Ok(execution) => Ok(execution),
-        Err(e) => Err(format!("Failed to execute clearance: {}", e)),
-    }
+pub async fn execute_clearance(risk_levels: Vec<String>, max_items: i32, dry_run: bool, state: tauri::State<'_, AppState>) -> Result<crate::api::ClearanceExecution, BackendError> {
+    let client = state.client();
+    Ok(client.execute_clearance(risk_levels, max_items, dry_run).await?)
 }
 
+/// Runs a dead-stock analysis and renders its line items as a CSV string.
 #[tauri::command]
-pub async fn get_risk_level_definitions() -> Result<crate::api::RiskLevelDefinitions, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.get_risk_level_definitions().await {
-        Ok(definitions) => Ok(definitions),
-        Err(e) => Err(format!("Failed to get risk level definitions: {}", e)),
-    }
+pub async fn export_dead_stock_csv(brand_filter: Option<String>, category_filter: Option<String>, min_risk_score: f64, state: tauri::State<'_, AppState>) -> Result<String, BackendError> {
+    let client = state.client();
+    let analysis = client.analyze_dead_stock(brand_filter, category_filter, min_risk_score).await?;
+    Ok(crate::csv_export::dead_stock_to_csv(&analysis)?)
 }
 
 #[tauri::command]
-pub async fn get_dead_stock_trends() -> Result<crate::api::DeadStockTrends, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.get_dead_stock_trends().await {
-        Ok(trends) => Ok(trends),
-        Err(e) => Err(format!("Failed to get dead stock trends: {}", e)),
-    }
+pub async fn evaluate_alerts(rules: Vec<crate::alerts::AlertRule>, state: tauri::State<'_, AppState>) -> Result<Vec<crate::alerts::Alert>, BackendError> {
+    let client = state.client();
+    Ok(client.evaluate_alerts(&rules).await?)
+}
+
+#[tauri::command]
+pub async fn subscribe_alerts(app_handle: tauri::AppHandle, state: tauri::State<'_, AppState>) -> Result<(), BackendError> {
+    use tauri::Manager;
+
+    let mut rx = state.client().register_alert_sink();
+    tokio::spawn(async move {
+        while let Some(alert) = rx.recv().await {
+            let _ = app_handle.emit_all("alerts://fired", alert);
+        }
+    });
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_risk_level_definitions(state: tauri::State<'_, AppState>) -> Result<crate::api::RiskLevelDefinitions, BackendError> {
+    let client = state.client();
+    Ok(client.get_risk_level_definitions().await?)
+}
+
+#[tauri::command]
+pub async fn get_dead_stock_trends(state: tauri::State<'_, AppState>) -> Result<crate::api::DeadStockTrends, BackendError> {
+    let client = state.client();
+    Ok(client.get_dead_stock_trends().await?)
 }
 
 // Predictive Insights Commands
 #[tauri::command]
-pub async fn get_predictive_insights(insight_types: Option<String>, days_ahead: i32, limit: i32) -> Result<Vec<PredictiveInsight>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.get_predictive_insights(insight_types, days_ahead, limit).await {
-        Ok(insights) => Ok(insights),
-        Err(e) => Err(format!("Failed to get predictive insights: {}", e)),
-    }
+pub async fn get_predictive_insights(insight_types: Option<String>, days_ahead: i32, limit: i32, state: tauri::State<'_, AppState>) -> Result<Vec<PredictiveInsight>, BackendError> {
+    let client = state.client();
+    Ok(client.get_predictive_insights_filtered(insight_types, days_ahead, limit).await?)
 }
 
 #[tauri::command]
-pub async fn get_inventory_forecasts(product_ids: Option<String>, horizon_days: i32) -> Result<Vec<InventoryForecast>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.get_inventory_forecasts(product_ids, horizon_days).await {
-        Ok(forecasts) => Ok(forecasts),
-        Err(e) => Err(format!("Failed to get inventory forecasts: {}", e)),
-    }
+pub async fn get_inventory_forecasts(product_ids: Option<String>, horizon_days: i32, state: tauri::State<'_, AppState>) -> Result<Vec<InventoryForecast>, BackendError> {
+    let client = state.client();
+    Ok(client.get_inventory_forecasts(product_ids, horizon_days).await?)
 }
 
 #[tauri::command]
-pub async fn get_restock_recommendations(investment_budget: Option<f64>, min_roi: f64, max_products: i32) -> Result<Vec<RestockRecommendation>, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.get_restock_recommendations(investment_budget, min_roi, max_products).await {
-        Ok(recommendations) => Ok(recommendations),
-        Err(e) => Err(format!("Failed to get restock recommendations: {}", e)),
-    }
+pub async fn get_inventory_forecasts_batched(
+    product_ids: Vec<String>,
+    horizon_days: i32,
+    state: tauri::State<'_, AppState>,
+) -> Result<crate::api::BatchResult<InventoryForecast>, BackendError> {
+    let client = state.client();
+    Ok(client.get_inventory_forecasts_batched(product_ids, horizon_days).await)
+}
+
+#[tauri::command]
+pub async fn get_predictive_insights_batched(
+    insight_types: Vec<String>,
+    days_ahead: i32,
+    limit: i32,
+    state: tauri::State<'_, AppState>,
+) -> Result<crate::api::BatchResult<PredictiveInsight>, BackendError> {
+    let client = state.client();
+    Ok(client.get_predictive_insights_batched(insight_types, days_ahead, limit).await)
+}
+
+#[tauri::command]
+pub async fn get_restock_recommendations(investment_budget: Option<f64>, min_roi: f64, max_products: i32, state: tauri::State<'_, AppState>) -> Result<Vec<RestockRecommendation>, BackendError> {
+    let client = state.client();
+    Ok(client.get_restock_recommendations(investment_budget, min_roi, max_products).await?)
+}
+
+#[tauri::command]
+pub async fn get_predictive_insights_summary(state: tauri::State<'_, AppState>) -> Result<PredictiveInsightsSummary, BackendError> {
+    let client = state.client();
+    Ok(client.get_predictive_insights_summary().await?)
+}
+
+// Config Commands
+#[tauri::command]
+pub async fn get_config(state: tauri::State<'_, AppState>) -> Result<AppConfig, BackendError> {
+    Ok(state.config())
+}
+
+#[tauri::command]
+pub async fn set_api_url(url: String, state: tauri::State<'_, AppState>) -> Result<AppConfig, BackendError> {
+    state.set_api_url(url);
+    Ok(state.config())
+}
+
+#[tauri::command]
+pub async fn sync_status(cache: tauri::State<'_, CacheDb>) -> Result<HashMap<String, chrono::DateTime<chrono::Utc>>, BackendError> {
+    cache.sync_status().await
 }
 
+// Streaming Commands
 #[tauri::command]
-pub async fn get_predictive_insights_summary() -> Result<PredictiveInsightsSummary, String> {
-    let client = ApiClient::new("http://localhost:8000".to_string());
-    match client.get_predictive_insights_summary().await {
-        Ok(summary) => Ok(summary),
-        Err(e) => Err(format!("Failed to get predictive insights summary: {}", e)),
+pub async fn subscribe_stream_topic(
+    topic: StreamTopic,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    stream_state: tauri::State<'_, crate::stream::StreamState>,
+) -> Result<(), BackendError> {
+    use tauri::Manager;
+
+    let mut guard = stream_state.0.lock().unwrap();
+    if guard.is_none() {
+        let (client, mut rx) = StreamClient::connect(&state.config().api_url);
+        let handle = app_handle.clone();
+        tokio::spawn(async move {
+            while let Some(message) = rx.recv().await {
+                let _ = handle.emit_all("stream://message", message);
+            }
+        });
+        *guard = Some(client);
     }
+
+    guard.as_ref().unwrap().subscribe(topic);
+    Ok(())
 }
 
+#[tauri::command]
+pub async fn get_stream_connection_state(stream_state: tauri::State<'_, crate::stream::StreamState>) -> Result<String, BackendError> {
+    let guard = stream_state.0.lock().unwrap();
+    let state = match guard.as_ref().map(|c| c.state()) {
+        Some(crate::stream::ConnectionState::Connected) => "connected",
+        Some(crate::stream::ConnectionState::Connecting) => "connecting",
+        Some(crate::stream::ConnectionState::Disconnected) | None => "disconnected",
+    };
+    Ok(state.to_string())
+}
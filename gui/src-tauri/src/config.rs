@@ -0,0 +1,66 @@
+use crate::api::ApiClient;
+use crate::builder::ClientBuilder;
+use std::sync::RwLock;
+
+/// Runtime-configurable backend connection settings.
+///
+/// Loaded once at startup from environment variables so the desktop app can be
+/// pointed at a staging or remote backend without recompiling, and mutated
+/// later via the `set_api_url` command.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AppConfig {
+    pub api_url: String,
+    pub api_token: Option<String>,
+}
+
+impl AppConfig {
+    pub fn from_env() -> Self {
+        Self {
+            api_url: std::env::var("SOLEFLIP_API_URL")
+                .unwrap_or_else(|_| "http://localhost:8000".to_string()),
+            api_token: std::env::var("SOLEFLIP_API_TOKEN").ok(),
+        }
+    }
+}
+
+/// Tauri-managed state shared across all commands: the current config and a
+/// single reused `ApiClient` built from it.
+pub struct AppState {
+    config: RwLock<AppConfig>,
+    client: RwLock<ApiClient>,
+}
+
+impl AppState {
+    pub fn new(config: AppConfig) -> Self {
+        let client = build_client(&config);
+        Self {
+            config: RwLock::new(config),
+            client: RwLock::new(client),
+        }
+    }
+
+    pub fn client(&self) -> ApiClient {
+        self.client.read().unwrap().clone()
+    }
+
+    pub fn config(&self) -> AppConfig {
+        self.config.read().unwrap().clone()
+    }
+
+    pub fn set_api_url(&self, api_url: String) {
+        let mut config = self.config.write().unwrap();
+        config.api_url = api_url;
+        *self.client.write().unwrap() = build_client(&config);
+    }
+}
+
+/// Builds the shared `ApiClient` from config via [`ClientBuilder`], so a
+/// configured `SOLEFLIP_API_TOKEN` is wired up as a bearer credential instead
+/// of every request assuming an open, unauthenticated localhost backend.
+fn build_client(config: &AppConfig) -> ApiClient {
+    let builder = ClientBuilder::custom(config.api_url.clone());
+    match &config.api_token {
+        Some(token) => builder.bearer_token(token.clone()).build(),
+        None => builder.build(),
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::{BTreeSet, HashMap};
+use std::io::Write;
+
+use serde_json::Value;
+
+use crate::api::DeadStockAnalysis;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CsvError {
+    #[error("failed to write CSV: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Writes `rows` as CSV to `writer`: a header row over the union of every
+/// row's keys (sorted for a stable column order), then one line per row.
+/// Nested objects/arrays are flattened into dotted-path columns (e.g.
+/// `shipment.address.city`) so a row stays on one CSV line.
+pub fn write_rows<W: Write>(rows: &[HashMap<String, Value>], writer: &mut W) -> Result<(), CsvError> {
+    let flattened: Vec<HashMap<String, String>> = rows.iter().map(flatten_row).collect();
+
+    let mut columns = BTreeSet::new();
+    for row in &flattened {
+        columns.extend(row.keys().cloned());
+    }
+    let columns: Vec<String> = columns.into_iter().collect();
+
+    write_line(writer, columns.iter().map(|c| c.as_str()))?;
+    for row in &flattened {
+        write_line(writer, columns.iter().map(|c| row.get(c).map(String::as_str).unwrap_or("")))?;
+    }
+    Ok(())
+}
+
+/// Writes `rows` as a CSV string, for callers (like `#[tauri::command]`
+/// handlers) that want a ready-to-save `String` rather than a `Write`.
+pub fn rows_to_csv(rows: &[HashMap<String, Value>]) -> Result<String, CsvError> {
+    let mut buf = Vec::new();
+    write_rows(rows, &mut buf)?;
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Flattens each `DeadStockItem` in `analysis.dead_stock_items` into a CSV
+/// row, the same way [`write_rows`] flattens arbitrary JSON rows.
+pub fn dead_stock_to_csv(analysis: &DeadStockAnalysis) -> Result<String, CsvError> {
+    let rows: Vec<HashMap<String, Value>> = analysis
+        .dead_stock_items
+        .iter()
+        .filter_map(|item| match serde_json::to_value(item) {
+            Ok(Value::Object(map)) => Some(map.into_iter().collect()),
+            _ => None,
+        })
+        .collect();
+    rows_to_csv(&rows)
+}
+
+fn flatten_row(row: &HashMap<String, Value>) -> HashMap<String, String> {
+    let mut flat = HashMap::new();
+    for (key, value) in row {
+        flatten_into(key, value, &mut flat);
+    }
+    flat
+}
+
+fn flatten_into(prefix: &str, value: &Value, out: &mut HashMap<String, String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                flatten_into(&format!("{}.{}", prefix, key), value, out);
+            }
+        }
+        Value::Array(items) => {
+            for (index, value) in items.iter().enumerate() {
+                flatten_into(&format!("{}.{}", prefix, index), value, out);
+            }
+        }
+        Value::Null => {
+            out.insert(prefix.to_string(), String::new());
+        }
+        Value::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
+
+fn write_line<'a, W: Write>(writer: &mut W, fields: impl Iterator<Item = &'a str>) -> Result<(), CsvError> {
+    let line = fields.map(escape).collect::<Vec<_>>().join(",");
+    writeln!(writer, "{}", line)?;
+    Ok(())
+}
+
+/// Quotes a field if it contains a comma, quote, or newline, per RFC 4180.
+fn escape(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn escapes_commas_quotes_and_newlines() {
+        assert_eq!(escape("plain"), "plain");
+        assert_eq!(escape("a,b"), "\"a,b\"");
+        assert_eq!(escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape("line1\nline2"), "\"line1\nline2\"");
+    }
+
+    #[test]
+    fn flattens_nested_objects_and_arrays_into_dotted_columns() {
+        let row: HashMap<String, Value> = [(
+            "shipment".to_string(),
+            json!({ "address": { "city": "Portland" }, "tags": ["fragile", "priority"] }),
+        )]
+        .into_iter()
+        .collect();
+
+        let flat = flatten_row(&row);
+        assert_eq!(flat.get("shipment.address.city"), Some(&"Portland".to_string()));
+        assert_eq!(flat.get("shipment.tags.0"), Some(&"fragile".to_string()));
+        assert_eq!(flat.get("shipment.tags.1"), Some(&"priority".to_string()));
+    }
+
+    #[test]
+    fn rows_to_csv_unions_columns_and_fills_missing_with_empty() {
+        let rows: Vec<HashMap<String, Value>> = vec![
+            [("a".to_string(), json!("1")), ("b".to_string(), json!("2"))].into_iter().collect(),
+            [("a".to_string(), json!("3"))].into_iter().collect(),
+        ];
+
+        let csv = rows_to_csv(&rows).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("a,b"));
+        assert_eq!(lines.next(), Some("1,2"));
+        assert_eq!(lines.next(), Some("3,"));
+    }
+
+    #[test]
+    fn dead_stock_to_csv_flattens_every_item() {
+        let analysis = DeadStockAnalysis {
+            analysis_id: "an-1".to_string(),
+            started_at: "2026-01-01T00:00:00Z".to_string(),
+            total_items_analyzed: 1,
+            filters_applied: HashMap::new(),
+            dead_stock_items: vec![crate::api::DeadStockItem {
+                item_id: "item-1".to_string(),
+                product_name: "Air Max".to_string(),
+                brand_name: "Nike".to_string(),
+                size_value: "10".to_string(),
+                purchase_price: 120.0,
+                current_market_price: None,
+                days_in_inventory: 200,
+                risk_score: 0.8,
+                risk_level: "high".to_string(),
+                locked_capital: 120.0,
+                potential_loss: 30.0,
+                recommended_actions: vec!["clearance".to_string()],
+                market_trend: None,
+                velocity_score: None,
+            }],
+            risk_summary: HashMap::new(),
+            financial_impact: crate::api::DeadStockDetailedFinancialImpact {
+                total_locked_capital: 120.0,
+                total_potential_loss: 30.0,
+                loss_percentage: 25.0,
+                locked_capital_by_risk: HashMap::new(),
+            },
+            recommendations: Vec::new(),
+            analysis_timestamp: "2026-01-01T00:00:00Z".to_string(),
+        };
+
+        let csv = dead_stock_to_csv(&analysis).unwrap();
+        assert!(csv.contains("item_id"));
+        assert!(csv.contains("item-1"));
+        assert!(csv.contains("Air Max"));
+    }
+}
@@ -0,0 +1,269 @@
+use crate::api::InventoryItem;
+use crate::error::BackendError;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Local SQLite cache so the app stays usable when the backend is unreachable.
+///
+/// Every successful fetch of inventory items, StockX listings, or dashboard
+/// metrics upserts into this cache; the corresponding commands fall back to it
+/// (tagging the response with `stale_since`) when the API call errors.
+///
+/// Queries are built with the runtime-checked `sqlx::query`/`query_as`
+/// functions rather than the `query!`/`query_as!` macros, since the latter
+/// need a live, already-migrated `DATABASE_URL` (or a committed offline
+/// cache) at `cargo build` time — neither of which this repo ships.
+#[derive(Clone)]
+pub struct CacheDb {
+    pool: SqlitePool,
+}
+
+/// Wraps a cached value with the timestamp it was last refreshed, so the
+/// frontend can show "data from 12 minutes ago" instead of pretending it's live.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Cached<T> {
+    pub data: T,
+    pub stale_since: Option<DateTime<Utc>>,
+}
+
+impl CacheDb {
+    pub async fn connect(app_data_dir: &Path) -> Result<Self, BackendError> {
+        std::fs::create_dir_all(app_data_dir)
+            .map_err(|e| BackendError::Config(format!("could not create app data dir: {}", e)))?;
+        let db_path = app_data_dir.join("cache.sqlite");
+        let url = format!("sqlite://{}?mode=rwc", db_path.display());
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(5)
+            .connect(&url)
+            .await
+            .map_err(|e| BackendError::Config(format!("failed to open offline cache: {}", e)))?;
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .map_err(|e| BackendError::Config(format!("failed to run cache migrations: {}", e)))?;
+
+        Ok(Self { pool })
+    }
+
+    pub async fn upsert_inventory_items(&self, items: &[InventoryItem]) -> Result<(), BackendError> {
+        let mut tx = self.pool.begin().await.map_err(sqlx_err)?;
+        for item in items {
+            sqlx::query(
+                r#"
+                INSERT INTO inventory_items
+                    (id, product_id, product_name, brand_name, category_name, size,
+                     quantity, purchase_price, purchase_date, supplier, status, notes,
+                     created_at, updated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                ON CONFLICT(id) DO UPDATE SET
+                    product_id = excluded.product_id,
+                    product_name = excluded.product_name,
+                    brand_name = excluded.brand_name,
+                    category_name = excluded.category_name,
+                    size = excluded.size,
+                    quantity = excluded.quantity,
+                    purchase_price = excluded.purchase_price,
+                    purchase_date = excluded.purchase_date,
+                    supplier = excluded.supplier,
+                    status = excluded.status,
+                    notes = excluded.notes,
+                    created_at = excluded.created_at,
+                    updated_at = excluded.updated_at
+                "#,
+            )
+            .bind(item.id)
+            .bind(item.product_id)
+            .bind(item.product_name.as_str())
+            .bind(item.brand_name.as_deref())
+            .bind(item.category_name.as_deref())
+            .bind(item.size.as_str())
+            .bind(item.quantity)
+            .bind(item.purchase_price)
+            .bind(item.purchase_date.as_deref())
+            .bind(item.supplier.as_str())
+            .bind(item.status.as_str())
+            .bind(item.notes.as_deref())
+            .bind(item.created_at.as_str())
+            .bind(item.updated_at.as_str())
+            .execute(&mut *tx)
+            .await
+            .map_err(sqlx_err)?;
+        }
+        tx.commit().await.map_err(sqlx_err)?;
+        self.touch_sync_status("inventory_items").await
+    }
+
+    pub async fn get_cached_inventory_items(&self, limit: Option<i32>) -> Result<Cached<Vec<InventoryItem>>, BackendError> {
+        let rows = sqlx::query_as::<_, InventoryItem>(
+            r#"
+            SELECT id, product_id, product_name, brand_name,
+                   category_name, size, quantity, purchase_price, purchase_date, supplier,
+                   status, notes, created_at, updated_at
+            FROM inventory_items
+            ORDER BY updated_at DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(limit.unwrap_or(i32::MAX) as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(sqlx_err)?;
+
+        Ok(Cached {
+            data: rows,
+            stale_since: self.last_synced("inventory_items").await?,
+        })
+    }
+
+    pub async fn delete_inventory_items(&self, ids: &[uuid::Uuid]) -> Result<(), BackendError> {
+        let mut tx = self.pool.begin().await.map_err(sqlx_err)?;
+        for id in ids {
+            sqlx::query("DELETE FROM inventory_items WHERE id = ?")
+                .bind(*id)
+                .execute(&mut *tx)
+                .await
+                .map_err(sqlx_err)?;
+        }
+        tx.commit().await.map_err(sqlx_err)?;
+        self.touch_sync_status("inventory_items").await
+    }
+
+    pub async fn upsert_stockx_listings(&self, listings: &[HashMap<String, Value>]) -> Result<(), BackendError> {
+        let mut tx = self.pool.begin().await.map_err(sqlx_err)?;
+        for listing in listings {
+            let item_id = listing
+                .get("item_id")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let status = listing.get("status").and_then(|v| v.as_str()).map(str::to_string);
+            let payload = serde_json::to_string(listing)?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO stockx_listings (item_id, status, payload)
+                VALUES (?, ?, ?)
+                ON CONFLICT(item_id) DO UPDATE SET status = excluded.status, payload = excluded.payload
+                "#,
+            )
+            .bind(item_id)
+            .bind(status)
+            .bind(payload)
+            .execute(&mut *tx)
+            .await
+            .map_err(sqlx_err)?;
+        }
+        tx.commit().await.map_err(sqlx_err)?;
+        self.touch_sync_status("stockx_listings").await
+    }
+
+    pub async fn get_cached_stockx_listings(&self) -> Result<Cached<Vec<HashMap<String, Value>>>, BackendError> {
+        let rows = sqlx::query("SELECT payload FROM stockx_listings")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(sqlx_err)?;
+
+        let data = rows
+            .into_iter()
+            .filter_map(|row| row.try_get::<String, _>("payload").ok())
+            .filter_map(|payload| serde_json::from_str(&payload).ok())
+            .collect();
+
+        Ok(Cached {
+            data,
+            stale_since: self.last_synced("stockx_listings").await?,
+        })
+    }
+
+    pub async fn upsert_dashboard_metrics(&self, metrics: &Value) -> Result<(), BackendError> {
+        let payload = serde_json::to_string(metrics)?;
+        sqlx::query(
+            r#"
+            INSERT INTO dashboard_metrics (id, payload) VALUES (0, ?)
+            ON CONFLICT(id) DO UPDATE SET payload = excluded.payload
+            "#,
+        )
+        .bind(payload)
+        .execute(&self.pool)
+        .await
+        .map_err(sqlx_err)?;
+        self.touch_sync_status("dashboard_metrics").await
+    }
+
+    pub async fn get_cached_dashboard_metrics(&self) -> Result<Cached<Value>, BackendError> {
+        let row = sqlx::query("SELECT payload FROM dashboard_metrics WHERE id = 0")
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(sqlx_err)?;
+
+        let data = match row {
+            Some(r) => {
+                let payload: String = r.try_get("payload").map_err(sqlx_err)?;
+                serde_json::from_str(&payload)?
+            }
+            None => Value::Null,
+        };
+
+        Ok(Cached {
+            data,
+            stale_since: self.last_synced("dashboard_metrics").await?,
+        })
+    }
+
+    pub async fn sync_status(&self) -> Result<HashMap<String, DateTime<Utc>>, BackendError> {
+        let rows = sqlx::query("SELECT table_name, last_synced_at FROM sync_status")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(sqlx_err)?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                let table_name: String = row.try_get("table_name").ok()?;
+                let last_synced_at: String = row.try_get("last_synced_at").ok()?;
+                DateTime::parse_from_rfc3339(&last_synced_at)
+                    .ok()
+                    .map(|ts| (table_name, ts.with_timezone(&Utc)))
+            })
+            .collect())
+    }
+
+    async fn touch_sync_status(&self, table: &str) -> Result<(), BackendError> {
+        let now = Utc::now().to_rfc3339();
+        sqlx::query(
+            r#"
+            INSERT INTO sync_status (table_name, last_synced_at) VALUES (?, ?)
+            ON CONFLICT(table_name) DO UPDATE SET last_synced_at = excluded.last_synced_at
+            "#,
+        )
+        .bind(table)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(sqlx_err)?;
+        Ok(())
+    }
+
+    async fn last_synced(&self, table: &str) -> Result<Option<DateTime<Utc>>, BackendError> {
+        let row = sqlx::query("SELECT last_synced_at FROM sync_status WHERE table_name = ?")
+            .bind(table)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(sqlx_err)?;
+
+        Ok(row
+            .and_then(|r| r.try_get::<String, _>("last_synced_at").ok())
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|ts| ts.with_timezone(&Utc)))
+    }
+}
+
+fn sqlx_err(e: sqlx::Error) -> BackendError {
+    BackendError::Config(format!("cache error: {}", e))
+}
@@ -0,0 +1,85 @@
+use serde::Serialize;
+
+/// Structured error returned to the frontend by every `#[tauri::command]`.
+///
+/// Serializes as a discriminated object (`{ "kind": "...", "message": ... }`)
+/// so the UI can branch on `kind` instead of pattern-matching on formatted strings.
+/// `message` is a string for the single-value variants and an object of the
+/// variant's fields for the structured ones (e.g. `{ "code": 500, "body": "..." }`).
+#[derive(Debug, thiserror::Error, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum BackendError {
+    #[error("backend unreachable: {0}")]
+    ApiUnreachable(String),
+
+    #[error("backend returned HTTP {code}")]
+    HttpStatus { code: u16, body: String },
+
+    #[error("backend rejected request (HTTP {status}): {message}")]
+    Api {
+        status: u16,
+        code: Option<String>,
+        message: String,
+    },
+
+    #[error("failed to deserialize response: {0}")]
+    Deserialize(String),
+
+    #[error("invalid batch id: {0}")]
+    InvalidBatchId(String),
+
+    #[error("query rejected: {reason}")]
+    QueryRejected { reason: String },
+
+    #[error("no price sample for {product_id} in the requested range")]
+    NoSampleInRange { product_id: String },
+
+    #[error("configuration error: {0}")]
+    Config(String),
+
+    #[error("failed to build CSV export: {0}")]
+    Csv(String),
+}
+
+impl From<crate::csv_export::CsvError> for BackendError {
+    fn from(err: crate::csv_export::CsvError) -> Self {
+        BackendError::Csv(err.to_string())
+    }
+}
+
+impl From<reqwest::Error> for BackendError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_decode() {
+            BackendError::Deserialize(err.to_string())
+        } else if let Some(status) = err.status() {
+            BackendError::HttpStatus {
+                code: status.as_u16(),
+                body: err.to_string(),
+            }
+        } else {
+            BackendError::ApiUnreachable(err.to_string())
+        }
+    }
+}
+
+impl From<crate::transport::TransportError> for BackendError {
+    fn from(err: crate::transport::TransportError) -> Self {
+        match err {
+            crate::transport::TransportError::Network(msg) => BackendError::ApiUnreachable(msg),
+            crate::transport::TransportError::Timeout => BackendError::ApiUnreachable("request timed out".to_string()),
+            crate::transport::TransportError::Decode(e) => BackendError::Deserialize(e.to_string()),
+        }
+    }
+}
+
+impl From<uuid::Error> for BackendError {
+    fn from(err: uuid::Error) -> Self {
+        BackendError::InvalidBatchId(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for BackendError {
+    fn from(err: serde_json::Error) -> Self {
+        BackendError::Deserialize(err.to_string())
+    }
+}
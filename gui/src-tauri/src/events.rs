@@ -0,0 +1,145 @@
+use crate::config::AppState;
+use crate::error::BackendError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio::task::AbortHandle;
+
+/// Emitted on the `import://progress` channel while a StockX import batch is running.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProgress {
+    pub batch_id: String,
+    pub processed: i32,
+    pub total: i32,
+    pub phase: String,
+}
+
+/// Emitted on the `enrichment://progress` channel while product enrichment is running.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnrichmentProgress {
+    pub completed: i64,
+    pub missing: i64,
+    pub phase: String,
+}
+
+/// Tracks the background polling tasks spawned by the subscribe commands so they
+/// can be cancelled on request instead of running until the batch finishes.
+#[derive(Default)]
+pub struct ProgressRegistry {
+    tasks: Mutex<HashMap<String, AbortHandle>>,
+}
+
+impl ProgressRegistry {
+    fn register(&self, key: String, handle: AbortHandle) {
+        self.tasks.lock().unwrap().insert(key, handle);
+    }
+
+    fn take(&self, key: &str) -> Option<AbortHandle> {
+        self.tasks.lock().unwrap().remove(key)
+    }
+}
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[tauri::command]
+pub async fn subscribe_import_progress(
+    batch_id: String,
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+    registry: State<'_, ProgressRegistry>,
+) -> Result<(), BackendError> {
+    let client = state.client();
+    let uuid = uuid::Uuid::parse_str(&batch_id)?;
+    let key = format!("import:{}", batch_id);
+
+    let task = tokio::spawn(async move {
+        loop {
+            match client.get_import_status(uuid).await {
+                Ok(status) => {
+                    let phase = status.status.clone();
+                    let _ = app_handle.emit_all(
+                        "import://progress",
+                        ImportProgress {
+                            batch_id: batch_id.clone(),
+                            processed: status.records_processed,
+                            total: status.records_processed + status.records_failed,
+                            phase: phase.clone(),
+                        },
+                    );
+
+                    if phase == "completed" || phase == "failed" || status.completed_at.is_some() {
+                        let _ = app_handle.emit_all("import://done", batch_id.clone());
+                        break;
+                    }
+                }
+                Err(e) => {
+                    let _ = app_handle.emit_all("import://error", e.to_string());
+                    break;
+                }
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    });
+
+    registry.register(key, task.abort_handle());
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn cancel_import_progress(batch_id: String, registry: State<'_, ProgressRegistry>) -> Result<(), BackendError> {
+    if let Some(handle) = registry.take(&format!("import:{}", batch_id)) {
+        handle.abort();
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn subscribe_enrichment_progress(
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+    registry: State<'_, ProgressRegistry>,
+) -> Result<(), BackendError> {
+    let client = state.client();
+    let key = "enrichment".to_string();
+
+    let task = tokio::spawn(async move {
+        loop {
+            match client.get_enrichment_status().await {
+                Ok(status) => {
+                    let sku = &status.enrichment_stats.sku;
+                    let _ = app_handle.emit_all(
+                        "enrichment://progress",
+                        EnrichmentProgress {
+                            completed: sku.completed,
+                            missing: sku.missing,
+                            phase: "running".to_string(),
+                        },
+                    );
+
+                    if status.overall_completion >= 100.0 {
+                        let _ = app_handle.emit_all("enrichment://done", ());
+                        break;
+                    }
+                }
+                Err(e) => {
+                    let _ = app_handle.emit_all("enrichment://error", e.to_string());
+                    break;
+                }
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    });
+
+    registry.register(key, task.abort_handle());
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn cancel_enrichment_progress(registry: State<'_, ProgressRegistry>) -> Result<(), BackendError> {
+    if let Some(handle) = registry.take("enrichment") {
+        handle.abort();
+    }
+    Ok(())
+}
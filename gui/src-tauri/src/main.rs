@@ -1,17 +1,59 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod alerts;
 mod api;
+mod auth;
+mod builder;
+mod cache;
 mod commands;
+mod config;
+mod csv_export;
+mod db;
+mod error;
+mod events;
+mod query_guard;
+mod rate_limit;
+mod search;
+mod stream;
+mod transport;
+mod tray;
 
-use tauri::generate_handler;
+use config::{AppConfig, AppState};
+use db::CacheDb;
+use events::ProgressRegistry;
+use search::SearchIndex;
+use tauri::{generate_handler, Manager};
 
 #[tokio::main]
 async fn main() {
+    let app_config = AppConfig::from_env();
+
     tauri::Builder::default()
+        .manage(AppState::new(app_config))
+        .manage(ProgressRegistry::default())
+        .manage(SearchIndex::default())
+        .manage(stream::StreamState::default())
+        .system_tray(tray::build())
+        .on_system_tray_event(|app, event| tray::handle_event(app, event))
+        .setup(|app| {
+            let app_data_dir = app
+                .path_resolver()
+                .app_data_dir()
+                .expect("app data dir must be resolvable");
+            let cache = tauri::async_runtime::block_on(CacheDb::connect(&app_data_dir))?;
+            app.manage(cache);
+
+            let handle = app.handle();
+            tauri::async_runtime::spawn(async move {
+                tray::refresh_tray_state(&handle).await;
+            });
+            Ok(())
+        })
         .invoke_handler(generate_handler![
             commands::health_check,
             commands::get_inventory_items,
+            commands::get_inventory_delta,
             commands::get_product_stats,
             commands::import_stockx_data,
             commands::get_import_status,
@@ -23,13 +65,30 @@ async fn main() {
             commands::start_product_enrichment,
             commands::get_pricing_recommendation,
             commands::get_market_analysis,
+            commands::get_market_analysis_at,
             commands::get_pricing_insights,
             commands::get_pricing_strategies,
             commands::generate_sales_forecast,
             commands::get_market_trends,
             commands::get_forecast_models,
-            commands::get_predictive_insights
+            commands::get_predictive_insights,
+            commands::get_inventory_forecasts_batched,
+            commands::get_predictive_insights_batched,
+            commands::get_config,
+            commands::set_api_url,
+            commands::sync_status,
+            commands::search_inventory,
+            commands::subscribe_stream_topic,
+            commands::get_stream_connection_state,
+            commands::evaluate_alerts,
+            commands::subscribe_alerts,
+            commands::export_listings_csv,
+            commands::export_dead_stock_csv,
+            events::subscribe_import_progress,
+            events::cancel_import_progress,
+            events::subscribe_enrichment_progress,
+            events::cancel_enrichment_progress
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
-}
\ No newline at end of file
+}
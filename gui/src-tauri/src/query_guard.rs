@@ -0,0 +1,354 @@
+use crate::error::BackendError;
+use sqlparser::ast::{
+    Expr, GroupByExpr, JoinConstraint, JoinOperator, Query, Select, SelectItem, SetExpr, Statement,
+    TableFactor, TableWithJoins,
+};
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
+
+/// Maximum rows a `run_database_query` call is allowed to return when the
+/// caller's query doesn't already specify its own `LIMIT`.
+const DEFAULT_ROW_LIMIT: u64 = 1000;
+
+/// Parses `input` as SQL and, if it is a single read-only `SELECT`/CTE
+/// statement, re-serializes it with a `LIMIT` enforced. Rejects anything that
+/// doesn't parse to exactly one `Query` statement, or that contains a
+/// mutating clause nested in CTEs, or in subqueries reachable from
+/// `WHERE`/`HAVING`/the select list/`GROUP BY`/a join's `ON` constraint/
+/// `ORDER BY`, not just `FROM`/`JOIN`, through the expression kinds that
+/// [`assert_expr_read_only`] searches — replacing the
+/// old `starts_with("select")` string check, which leading comments, CTEs,
+/// or stacked statements could defeat.
+pub fn validate_read_only(input: &str) -> Result<String, BackendError> {
+    let dialect = GenericDialect {};
+    let statements = Parser::parse_sql(&dialect, input).map_err(|e| reject(format!("failed to parse SQL: {}", e)))?;
+
+    let [statement] = statements.as_slice() else {
+        return Err(reject(format!(
+            "expected exactly one statement, found {}",
+            statements.len()
+        )));
+    };
+
+    let Statement::Query(query) = statement else {
+        return Err(reject("only SELECT/CTE queries are allowed".to_string()));
+    };
+
+    assert_read_only(query)?;
+
+    let mut query = query.as_ref().clone();
+    if query.limit.is_none() {
+        query.limit = Some(sqlparser::ast::Expr::Value(sqlparser::ast::Value::Number(
+            DEFAULT_ROW_LIMIT.to_string(),
+            false,
+        )));
+    }
+
+    Ok(query.to_string())
+}
+
+fn reject(reason: String) -> BackendError {
+    BackendError::QueryRejected { reason }
+}
+
+fn assert_read_only(query: &Query) -> Result<(), BackendError> {
+    if let Some(with) = &query.with {
+        for cte in &with.cte_tables {
+            assert_read_only(&cte.query)?;
+        }
+    }
+
+    assert_set_expr_read_only(&query.body)?;
+
+    for order_by in &query.order_by {
+        assert_expr_read_only(&order_by.expr)?;
+    }
+
+    Ok(())
+}
+
+fn assert_set_expr_read_only(body: &SetExpr) -> Result<(), BackendError> {
+    match body {
+        SetExpr::Select(select) => assert_select_read_only(select),
+        SetExpr::Query(query) => assert_read_only(query),
+        SetExpr::SetOperation { left, right, .. } => {
+            assert_set_expr_read_only(left)?;
+            assert_set_expr_read_only(right)
+        }
+        SetExpr::Values(_) => Ok(()),
+        SetExpr::Insert(_) | SetExpr::Update(_) => {
+            Err(reject("mutating statement nested inside a query".to_string()))
+        }
+        SetExpr::Table(_) => Ok(()),
+    }
+}
+
+/// Checks every clause of a `SELECT` that can carry a subquery — `FROM`/`JOIN`
+/// table factors, the select list, `WHERE`, `GROUP BY`, and `HAVING` — not
+/// just the tables in `FROM`.
+fn assert_select_read_only(select: &Select) -> Result<(), BackendError> {
+    for table in &select.from {
+        assert_table_with_joins_read_only(table)?;
+    }
+
+    for item in &select.projection {
+        assert_select_item_read_only(item)?;
+    }
+
+    if let Some(selection) = &select.selection {
+        assert_expr_read_only(selection)?;
+    }
+
+    match &select.group_by {
+        GroupByExpr::All => {}
+        GroupByExpr::Expressions(exprs) => {
+            for expr in exprs {
+                assert_expr_read_only(expr)?;
+            }
+        }
+    }
+
+    if let Some(having) = &select.having {
+        assert_expr_read_only(having)?;
+    }
+
+    Ok(())
+}
+
+fn assert_select_item_read_only(item: &SelectItem) -> Result<(), BackendError> {
+    match item {
+        SelectItem::UnnamedExpr(expr) => assert_expr_read_only(expr),
+        SelectItem::ExprWithAlias { expr, .. } => assert_expr_read_only(expr),
+        SelectItem::QualifiedWildcard(..) | SelectItem::Wildcard(..) => Ok(()),
+    }
+}
+
+fn assert_table_with_joins_read_only(table: &TableWithJoins) -> Result<(), BackendError> {
+    assert_table_factor_read_only(&table.relation)?;
+    for join in &table.joins {
+        assert_table_factor_read_only(&join.relation)?;
+        assert_join_operator_read_only(&join.join_operator)?;
+    }
+    Ok(())
+}
+
+/// Checks the `ON <expr>` constraint of a join, since a mutating CTE/subquery
+/// can be smuggled there just as easily as into `WHERE`/`HAVING`.
+fn assert_join_operator_read_only(operator: &JoinOperator) -> Result<(), BackendError> {
+    match operator {
+        JoinOperator::Inner(constraint)
+        | JoinOperator::LeftOuter(constraint)
+        | JoinOperator::RightOuter(constraint)
+        | JoinOperator::FullOuter(constraint)
+        | JoinOperator::LeftSemi(constraint)
+        | JoinOperator::RightSemi(constraint)
+        | JoinOperator::LeftAnti(constraint)
+        | JoinOperator::RightAnti(constraint) => assert_join_constraint_read_only(constraint),
+        JoinOperator::CrossJoin | JoinOperator::CrossApply | JoinOperator::OuterApply => Ok(()),
+    }
+}
+
+fn assert_join_constraint_read_only(constraint: &JoinConstraint) -> Result<(), BackendError> {
+    match constraint {
+        JoinConstraint::On(expr) => assert_expr_read_only(expr),
+        JoinConstraint::Using(_) | JoinConstraint::Natural | JoinConstraint::None => Ok(()),
+    }
+}
+
+fn assert_table_factor_read_only(table: &TableFactor) -> Result<(), BackendError> {
+    match table {
+        TableFactor::Derived { subquery, .. } => assert_read_only(subquery),
+        TableFactor::NestedJoin { table_with_joins, .. } => assert_table_with_joins_read_only(table_with_joins),
+        _ => Ok(()),
+    }
+}
+
+/// Recurses into the `Expr` variants listed below: those carrying a nested
+/// `Query` (`Subquery`, `ArraySubquery`, `InSubquery`, `Exists`) and those
+/// wrapping other expressions (`BinaryOp`, `UnaryOp`, `Nested`, casts,
+/// `IS [NOT] NULL/TRUE/FALSE`, `COLLATE`, `BETWEEN`, `IN (list)`,
+/// `[I]LIKE`, `CASE`, tuples, function arguments), so a mutating CTE inside
+/// any nesting of these is caught — e.g.
+/// `WHERE x = 1 AND EXISTS (WITH c AS (UPDATE ...) SELECT 1 FROM c)`.
+/// Every other `Expr` variant is accepted without searching its operands.
+fn assert_expr_read_only(expr: &Expr) -> Result<(), BackendError> {
+    match expr {
+        Expr::Subquery(query) | Expr::ArraySubquery(query) => assert_read_only(query),
+        Expr::InSubquery { expr, subquery, .. } => {
+            assert_expr_read_only(expr)?;
+            assert_read_only(subquery)
+        }
+        Expr::Exists { subquery, .. } => assert_read_only(subquery),
+        Expr::BinaryOp { left, right, .. } => {
+            assert_expr_read_only(left)?;
+            assert_expr_read_only(right)
+        }
+        Expr::UnaryOp { expr, .. }
+        | Expr::Nested(expr)
+        | Expr::Cast { expr, .. }
+        | Expr::TryCast { expr, .. }
+        | Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::IsTrue(expr)
+        | Expr::IsNotTrue(expr)
+        | Expr::IsFalse(expr)
+        | Expr::IsNotFalse(expr)
+        | Expr::Collate { expr, .. } => assert_expr_read_only(expr),
+        Expr::Between { expr, low, high, .. } => {
+            assert_expr_read_only(expr)?;
+            assert_expr_read_only(low)?;
+            assert_expr_read_only(high)
+        }
+        Expr::InList { expr, list, .. } => {
+            assert_expr_read_only(expr)?;
+            for item in list {
+                assert_expr_read_only(item)?;
+            }
+            Ok(())
+        }
+        Expr::Like { expr, pattern, .. } | Expr::ILike { expr, pattern, .. } => {
+            assert_expr_read_only(expr)?;
+            assert_expr_read_only(pattern)
+        }
+        Expr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => {
+            if let Some(operand) = operand {
+                assert_expr_read_only(operand)?;
+            }
+            for expr in conditions.iter().chain(results.iter()) {
+                assert_expr_read_only(expr)?;
+            }
+            if let Some(else_result) = else_result {
+                assert_expr_read_only(else_result)?;
+            }
+            Ok(())
+        }
+        Expr::Tuple(exprs) => {
+            for expr in exprs {
+                assert_expr_read_only(expr)?;
+            }
+            Ok(())
+        }
+        Expr::Function(function) => {
+            for arg in &function.args {
+                assert_function_arg_read_only(arg)?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn assert_function_arg_read_only(arg: &sqlparser::ast::FunctionArg) -> Result<(), BackendError> {
+    use sqlparser::ast::{FunctionArg, FunctionArgExpr};
+
+    let expr = match arg {
+        FunctionArg::Named { arg, .. } | FunctionArg::Unnamed(arg) => arg,
+    };
+    match expr {
+        FunctionArgExpr::Expr(expr) => assert_expr_read_only(expr),
+        FunctionArgExpr::QualifiedWildcard(_) | FunctionArgExpr::Wildcard => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_plain_select() {
+        let result = validate_read_only("SELECT * FROM inventory_items WHERE status = 'active'");
+        assert!(result.is_ok());
+        assert!(result.unwrap().to_lowercase().contains("limit"));
+    }
+
+    #[test]
+    fn allows_read_only_cte() {
+        let result = validate_read_only(
+            "WITH recent AS (SELECT * FROM inventory_items WHERE days_in_inventory < 30) SELECT * FROM recent",
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn rejects_stacked_statements() {
+        let result = validate_read_only("SELECT * FROM inventory_items; DROP TABLE inventory_items;");
+        assert!(matches!(result, Err(BackendError::QueryRejected { .. })));
+    }
+
+    #[test]
+    fn rejects_leading_comment_smuggling_a_mutation() {
+        let result = validate_read_only(
+            "/* just a read */ UPDATE inventory_items SET quantity = 0 -- SELECT * FROM inventory_items",
+        );
+        assert!(matches!(result, Err(BackendError::QueryRejected { .. })));
+    }
+
+    #[test]
+    fn rejects_mutating_top_level_cte() {
+        let result = validate_read_only(
+            "WITH x AS (UPDATE inventory_items SET quantity = 0 RETURNING 1) SELECT * FROM x",
+        );
+        assert!(matches!(result, Err(BackendError::QueryRejected { .. })));
+    }
+
+    #[test]
+    fn rejects_mutating_cte_nested_in_a_where_subquery() {
+        let result = validate_read_only(
+            "SELECT * FROM inventory_items WHERE EXISTS (WITH x AS (UPDATE inventory_items SET quantity = 0 RETURNING 1) SELECT 1 FROM x)",
+        );
+        assert!(matches!(result, Err(BackendError::QueryRejected { .. })));
+    }
+
+    #[test]
+    fn rejects_mutating_cte_nested_in_an_in_subquery() {
+        let result = validate_read_only(
+            "SELECT * FROM inventory_items WHERE item_id IN (WITH x AS (UPDATE inventory_items SET quantity = 0 RETURNING item_id) SELECT item_id FROM x)",
+        );
+        assert!(matches!(result, Err(BackendError::QueryRejected { .. })));
+    }
+
+    #[test]
+    fn rejects_mutating_cte_nested_in_a_having_subquery() {
+        let result = validate_read_only(
+            "SELECT brand_name, COUNT(*) FROM inventory_items GROUP BY brand_name HAVING COUNT(*) > (WITH x AS (UPDATE inventory_items SET quantity = 0 RETURNING 1) SELECT COUNT(*) FROM x)",
+        );
+        assert!(matches!(result, Err(BackendError::QueryRejected { .. })));
+    }
+
+    #[test]
+    fn rejects_mutating_cte_nested_in_a_projection_subquery() {
+        let result = validate_read_only(
+            "SELECT (WITH x AS (UPDATE inventory_items SET quantity = 0 RETURNING 1) SELECT COUNT(*) FROM x) FROM inventory_items",
+        );
+        assert!(matches!(result, Err(BackendError::QueryRejected { .. })));
+    }
+
+    #[test]
+    fn rejects_mutating_nested_join_subquery() {
+        let result = validate_read_only(
+            "SELECT * FROM (inventory_items JOIN (WITH x AS (UPDATE inventory_items SET quantity = 0 RETURNING 1) SELECT 1 AS item_id FROM x) AS y ON true)",
+        );
+        assert!(matches!(result, Err(BackendError::QueryRejected { .. })));
+    }
+
+    #[test]
+    fn rejects_mutating_cte_nested_in_a_join_on_constraint() {
+        let result = validate_read_only(
+            "SELECT * FROM inventory_items JOIN suppliers ON suppliers.id = (WITH x AS (UPDATE inventory_items SET quantity = 0 RETURNING id) SELECT id FROM x LIMIT 1)",
+        );
+        assert!(matches!(result, Err(BackendError::QueryRejected { .. })));
+    }
+
+    #[test]
+    fn rejects_mutating_cte_nested_in_an_order_by_expr() {
+        let result = validate_read_only(
+            "SELECT * FROM inventory_items ORDER BY (WITH x AS (UPDATE inventory_items SET quantity = 0 RETURNING 1) SELECT 1 FROM x)",
+        );
+        assert!(matches!(result, Err(BackendError::QueryRejected { .. })));
+    }
+}
@@ -0,0 +1,169 @@
+use rand::Rng;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Token-bucket rate limiter shared by every request an `ApiClient` makes, so
+/// a burst of pricing/forecast calls can't hammer the backend.
+pub struct RateLimiter {
+    capacity: f64,
+    refill_rate: f64, // tokens per second
+    state: Mutex<RateLimiterState>,
+}
+
+struct RateLimiterState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// `max_requests` tokens refill fully every `interval`.
+    pub fn new(max_requests: u32, interval: Duration) -> Self {
+        let capacity = max_requests as f64;
+        Self {
+            capacity,
+            refill_rate: capacity / interval.as_secs_f64(),
+            state: Mutex::new(RateLimiterState {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Blocks until a token is available, then consumes it.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                let elapsed = state.last_refill.elapsed().as_secs_f64();
+                state.tokens = (state.tokens + elapsed * self.refill_rate).min(self.capacity);
+                state.last_refill = Instant::now();
+
+                if state.tokens >= 1.0 {
+                    state.tokens -= 1.0;
+                    None
+                } else {
+                    Some(Duration::from_secs_f64((1.0 - state.tokens) / self.refill_rate))
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        // 10 requests/sec sustained by default — generous enough not to throttle
+        // normal usage, tight enough to stop an accidental hot loop.
+        Self::new(10, Duration::from_secs(1))
+    }
+}
+
+/// Exponential backoff with jitter for transient failures (429/502/503/timeouts).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `base * 2^attempt`, capped at `max_delay`, with ±20% jitter.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay.as_secs_f64() * 2f64.powi(attempt as i32);
+        let capped = exp.min(self.max_delay.as_secs_f64());
+        let jitter = rand::thread_rng().gen_range(0.8..1.2);
+        Duration::from_secs_f64(capped * jitter)
+    }
+
+    pub fn should_retry(&self, attempt: u32, status: Option<u16>, is_transport_timeout: bool) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        is_transport_timeout || matches!(status, Some(429) | Some(502) | Some(503))
+    }
+}
+
+/// Parses a `Retry-After` header (seconds form) into a concrete delay.
+pub fn retry_after_delay(response: &crate::transport::HttpResponse) -> Option<Duration> {
+    response
+        .header("retry-after")
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::HttpResponse;
+    use std::collections::HashMap;
+
+    fn response(status: u16, headers: &[(&str, &str)]) -> HttpResponse {
+        HttpResponse {
+            status,
+            headers: headers.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            body: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn should_retry_stops_once_max_attempts_are_exhausted() {
+        let policy = RetryPolicy { max_attempts: 2, ..RetryPolicy::default() };
+        assert!(policy.should_retry(0, Some(503), false));
+        assert!(policy.should_retry(1, Some(503), false));
+        assert!(!policy.should_retry(2, Some(503), false));
+    }
+
+    #[test]
+    fn should_retry_only_for_transient_statuses_or_transport_timeouts() {
+        let policy = RetryPolicy::default();
+        assert!(policy.should_retry(0, Some(429), false));
+        assert!(policy.should_retry(0, Some(502), false));
+        assert!(policy.should_retry(0, Some(503), false));
+        assert!(policy.should_retry(0, None, true));
+        assert!(!policy.should_retry(0, Some(404), false));
+        assert!(!policy.should_retry(0, Some(200), false));
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_and_respects_the_cap() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+        };
+
+        // attempt 0: base * 2^0 = 100ms, with ±20% jitter.
+        let first = policy.backoff(0);
+        assert!(first >= Duration::from_millis(80) && first <= Duration::from_millis(120));
+
+        // Uncapped this would be 100ms * 2^10 — the cap (500ms ±20%) must still hold.
+        let later = policy.backoff(10);
+        assert!(later >= Duration::from_millis(400) && later <= Duration::from_millis(600));
+    }
+
+    #[test]
+    fn retry_after_delay_parses_the_seconds_header() {
+        let resp = response(429, &[("retry-after", "7")]);
+        assert_eq!(retry_after_delay(&resp), Some(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn retry_after_delay_is_none_when_header_is_missing_or_unparseable() {
+        assert_eq!(retry_after_delay(&response(429, &[])), None);
+        assert_eq!(retry_after_delay(&response(429, &[("retry-after", "soon")])), None);
+    }
+}
@@ -0,0 +1,347 @@
+use crate::api::InventoryItem;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+
+/// Per-field weight applied when ranking a match, so a hit on the SKU-ish
+/// product name or brand outranks the same term showing up in free-text notes.
+const FIELD_WEIGHTS: &[(&str, f64)] = &[
+    ("product_name", 3.0),
+    ("brand_name", 3.0),
+    ("category_name", 1.5),
+    ("supplier", 1.0),
+    ("notes", 0.5),
+];
+
+#[derive(Debug, Clone)]
+struct IndexedField {
+    field: &'static str,
+    tokens: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedItem {
+    item: InventoryItem,
+    fields: Vec<IndexedField>,
+}
+
+/// In-process inverted index over an inventory snapshot, rebuilt whenever
+/// fresh inventory is fetched. Supports prefix and bounded-edit-distance
+/// matching so typos in a search query still surface the right items.
+#[derive(Default)]
+pub struct SearchIndex {
+    inner: RwLock<Option<IndexState>>,
+}
+
+struct IndexState {
+    items: Vec<IndexedItem>,
+    postings: HashMap<String, HashSet<usize>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub item: InventoryItem,
+    pub score: f64,
+    pub matched_terms: usize,
+    pub typos: usize,
+}
+
+impl SearchIndex {
+    pub fn rebuild(&self, items: &[InventoryItem]) {
+        let mut postings: HashMap<String, HashSet<usize>> = HashMap::new();
+        let mut indexed = Vec::with_capacity(items.len());
+
+        for (idx, item) in items.iter().enumerate() {
+            let mut fields = Vec::new();
+            for (field, raw) in [
+                ("product_name", Some(item.product_name.as_str())),
+                ("brand_name", item.brand_name.as_deref()),
+                ("category_name", item.category_name.as_deref()),
+                ("supplier", Some(item.supplier.as_str())),
+                ("notes", item.notes.as_deref()),
+            ] {
+                let Some(raw) = raw else { continue };
+                let tokens = tokenize(raw);
+                for token in &tokens {
+                    postings.entry(token.clone()).or_default().insert(idx);
+                }
+                fields.push(IndexedField { field, tokens });
+            }
+            indexed.push(IndexedItem {
+                item: item.clone(),
+                fields,
+            });
+        }
+
+        *self.inner.write().unwrap() = Some(IndexState {
+            items: indexed,
+            postings,
+        });
+    }
+
+    pub fn search(&self, query: &str, top_n: usize) -> Vec<SearchHit> {
+        let guard = self.inner.read().unwrap();
+        let Some(state) = guard.as_ref() else { return Vec::new() };
+
+        let query_terms = tokenize(query);
+        if query_terms.is_empty() {
+            return Vec::new();
+        }
+
+        // item_idx -> per-term match info: which query term matched, how many
+        // edits it cost, and where in the document it was found (for proximity).
+        let mut candidates: HashMap<usize, Vec<TermMatch>> = HashMap::new();
+
+        for (term_idx, term) in query_terms.iter().enumerate() {
+            let max_edits = allowed_edits(term);
+
+            for (token, item_idxs) in &state.postings {
+                let distance = if token.starts_with(term.as_str()) || term.starts_with(token.as_str()) {
+                    0
+                } else {
+                    bounded_levenshtein(term, token, max_edits)
+                };
+
+                if let Some(distance) = distance {
+                    for &idx in item_idxs {
+                        for (field_idx, pos) in token_positions(&state.items[idx], token) {
+                            candidates.entry(idx).or_default().push(TermMatch {
+                                term_idx,
+                                distance,
+                                field_idx,
+                                pos,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut results: Vec<(SearchHit, usize, f64)> = candidates
+            .into_iter()
+            .map(|(idx, matches)| {
+                let matched_terms: HashSet<usize> = matches.iter().map(|m| m.term_idx).collect();
+                let total_typos: usize = matches.iter().map(|m| m.distance).sum();
+                let proximity = min_span(&matches);
+                let field_boost = field_weight_boost(&state.items[idx], &query_terms);
+
+                let score = matched_terms.len() as f64 * 100.0
+                    - total_typos as f64 * 10.0
+                    - proximity as f64 * 0.1
+                    + field_boost;
+
+                (
+                    SearchHit {
+                        item: state.items[idx].item.clone(),
+                        score,
+                        matched_terms: matched_terms.len(),
+                        typos: total_typos,
+                    },
+                    proximity,
+                    field_boost,
+                )
+            })
+            .collect();
+
+        // Ranking precedence: most matched terms, then fewest typos, then
+        // tightest term proximity (smallest token-position span), then
+        // highest field-weight boost — proximity must outrank field boost,
+        // not be folded into the same score tier.
+        results.sort_by(|(a, a_proximity, a_boost), (b, b_proximity, b_boost)| {
+            b.matched_terms
+                .cmp(&a.matched_terms)
+                .then(a.typos.cmp(&b.typos))
+                .then(a_proximity.cmp(b_proximity))
+                .then(b_boost.partial_cmp(a_boost).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        results.truncate(top_n);
+        results.into_iter().map(|(hit, _, _)| hit).collect()
+    }
+}
+
+/// A single query term matching a token at a specific field/position in a
+/// document, with the edit distance it cost to match.
+struct TermMatch {
+    term_idx: usize,
+    distance: usize,
+    field_idx: usize,
+    pos: usize,
+}
+
+/// Positions (field index, token index within that field) where `token`
+/// occurs in `item`, so a match can be located for proximity scoring.
+fn token_positions<'a>(item: &'a IndexedItem, token: &'a str) -> impl Iterator<Item = (usize, usize)> + 'a {
+    item.fields.iter().enumerate().flat_map(move |(field_idx, field)| {
+        field
+            .tokens
+            .iter()
+            .enumerate()
+            .filter_map(move |(pos, t)| (t == token).then_some((field_idx, pos)))
+    })
+}
+
+/// The minimum token-position span covering the most distinct query terms:
+/// groups matches by field, and for each field's matches takes the distance
+/// between the earliest and latest matched position. Among fields, prefers
+/// the one covering the most distinct terms, tie-broken by the smallest span.
+/// A single matched term (in any field) has zero span.
+fn min_span(matches: &[TermMatch]) -> usize {
+    let mut by_field: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+    for m in matches {
+        by_field.entry(m.field_idx).or_default().push((m.term_idx, m.pos));
+    }
+
+    let mut best: Option<(usize, usize)> = None; // (distinct terms covered, span)
+    for entries in by_field.values() {
+        let distinct_terms: HashSet<usize> = entries.iter().map(|(t, _)| *t).collect();
+        let positions: Vec<usize> = entries.iter().map(|(_, pos)| *pos).collect();
+        let span = positions.iter().max().copied().unwrap_or(0) - positions.iter().min().copied().unwrap_or(0);
+        let candidate = (distinct_terms.len(), span);
+
+        best = Some(match best {
+            Some(current) if current.0 > candidate.0 || (current.0 == candidate.0 && current.1 <= candidate.1) => current,
+            _ => candidate,
+        });
+    }
+
+    best.map(|(_, span)| span).unwrap_or(0)
+}
+
+fn field_weight_boost(item: &IndexedItem, query_terms: &[String]) -> f64 {
+    let mut boost = 0.0;
+    for field in &item.fields {
+        let weight = FIELD_WEIGHTS
+            .iter()
+            .find(|(name, _)| *name == field.field)
+            .map(|(_, w)| *w)
+            .unwrap_or(1.0);
+        for token in &field.tokens {
+            if query_terms.iter().any(|q| token.starts_with(q.as_str())) {
+                boost += weight;
+            }
+        }
+    }
+    boost
+}
+
+fn allowed_edits(term: &str) -> usize {
+    match term.chars().count() {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Levenshtein distance, short-circuiting (returning `None`) once it's
+/// provably larger than `max`, so the index scan stays cheap.
+fn bounded_levenshtein(a: &str, b: &str, max: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut cur = vec![0usize; b.len() + 1];
+        cur[0] = i;
+        let mut row_min = cur[0];
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            cur[j] = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+            row_min = row_min.min(cur[j]);
+        }
+        if row_min > max {
+            return None;
+        }
+        prev = cur;
+    }
+
+    let distance = prev[b.len()];
+    (distance <= max).then_some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn item(id: u128, product_name: &str, notes: Option<&str>) -> InventoryItem {
+        InventoryItem {
+            id: Uuid::from_u128(id),
+            product_id: None,
+            product_name: product_name.to_string(),
+            brand_name: None,
+            category_name: None,
+            size: "10".to_string(),
+            quantity: 1,
+            purchase_price: None,
+            purchase_date: None,
+            supplier: "acme".to_string(),
+            status: "active".to_string(),
+            notes: notes.map(str::to_string),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            updated_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn matches_exact_terms_in_the_product_name() {
+        let index = SearchIndex::default();
+        index.rebuild(&[item(1, "Air Max 90", None)]);
+
+        let hits = index.search("air max", 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].matched_terms, 2);
+        assert_eq!(hits[0].typos, 0);
+    }
+
+    #[test]
+    fn tolerates_a_single_typo_within_the_allowed_edit_budget() {
+        let index = SearchIndex::default();
+        index.rebuild(&[item(1, "Sneaker Vault", None)]);
+
+        // "sneakr" (6 chars) falls in the 5..=8 bucket, which allows 1 edit.
+        let hits = index.search("sneakr vault", 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].matched_terms, 2);
+        assert_eq!(hits[0].typos, 1);
+    }
+
+    #[test]
+    fn ignores_terms_that_exceed_the_allowed_edit_budget() {
+        let index = SearchIndex::default();
+        index.rebuild(&[item(1, "Sneaker Vault", None)]);
+
+        // "zzzzzz" is nowhere near "sneaker" or "vault" within the allowed edits.
+        let hits = index.search("zzzzzz", 10);
+        assert!(hits.is_empty());
+    }
+
+    #[test]
+    fn tighter_token_proximity_outranks_a_larger_field_weight_boost() {
+        let index = SearchIndex::default();
+        // Tight span (2 tokens apart) but in the low-weight `notes` field.
+        let tight = item(1, "Item One", Some("red suede shoe"));
+        // Loose span (5 tokens apart) but in the high-weight `product_name` field.
+        let loose = item(2, "red extra padding very special shoe", None);
+        index.rebuild(&[tight, loose]);
+
+        let hits = index.search("red shoe", 10);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].matched_terms, 2);
+        assert_eq!(hits[1].matched_terms, 2);
+        // Despite the tight match's much smaller field-weight boost, proximity
+        // is a higher-precedence tier and must decide the ranking first.
+        assert_eq!(hits[0].item.id, Uuid::from_u128(1));
+    }
+}
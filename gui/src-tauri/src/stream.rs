@@ -0,0 +1,146 @@
+use crate::api::{AutoListingExecution, ImportStatus, MarketAnalysis};
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+/// Tauri-managed holder for the single shared `StreamClient`, created lazily
+/// the first time a command asks to subscribe to something.
+#[derive(Default)]
+pub struct StreamState(pub Mutex<Option<StreamClient>>);
+
+/// A channel a caller can subscribe to on the backend's `/ws` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "topic", content = "params")]
+pub enum StreamTopic {
+    ImportProgress { batch_id: String },
+    MarketPrice { product_id: String },
+    AutoListingExecution,
+}
+
+/// Messages delivered over the socket, mirroring the REST response shapes so
+/// a subscriber can reuse the same structs it already deserializes elsewhere.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum StreamMessage {
+    ImportStatus(ImportStatus),
+    MarketPrice(MarketAnalysis),
+    AutoListingExecution(AutoListingExecution),
+    Pong,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connecting,
+    Connected,
+    Disconnected,
+}
+
+const PING_INTERVAL: Duration = Duration::from_secs(15);
+const RECONNECT_DELAY: Duration = Duration::from_secs(3);
+
+/// WebSocket client for `/ws` topic subscriptions. Reconnects and resubscribes
+/// automatically when the socket drops; callers should fall back to REST
+/// polling if [`StreamClient::state`] reports `Disconnected` for too long.
+pub struct StreamClient {
+    ws_url: String,
+    state: Arc<std::sync::RwLock<ConnectionState>>,
+    subscriptions: Arc<std::sync::Mutex<Vec<StreamTopic>>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl StreamClient {
+    /// Connects to `{base_url}/ws` (http(s) is rewritten to ws(s)) and returns
+    /// the client plus a channel that yields every message received, across
+    /// reconnects. The loop stops reconnecting once [`StreamClient::close`]
+    /// has been called and the current connection ends; dropping the client
+    /// does not stop it.
+    pub fn connect(base_url: &str) -> (Self, mpsc::UnboundedReceiver<StreamMessage>) {
+        let ws_url = format!("{}/ws", base_url.replacen("http", "ws", 1));
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let client = Self {
+            ws_url,
+            state: Arc::new(std::sync::RwLock::new(ConnectionState::Connecting)),
+            subscriptions: Arc::new(std::sync::Mutex::new(Vec::new())),
+            shutdown: Arc::new(AtomicBool::new(false)),
+        };
+
+        client.spawn_connection_loop(tx);
+        (client, rx)
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        *self.state.read().unwrap()
+    }
+
+    pub fn subscribe(&self, topic: StreamTopic) {
+        self.subscriptions.lock().unwrap().push(topic);
+    }
+
+    pub fn close(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+    }
+
+    fn spawn_connection_loop(&self, tx: mpsc::UnboundedSender<StreamMessage>) {
+        let ws_url = self.ws_url.clone();
+        let state = Arc::clone(&self.state);
+        let subscriptions = Arc::clone(&self.subscriptions);
+        let shutdown = Arc::clone(&self.shutdown);
+
+        tokio::spawn(async move {
+            while !shutdown.load(Ordering::SeqCst) {
+                *state.write().unwrap() = ConnectionState::Connecting;
+
+                match tokio_tungstenite::connect_async(&ws_url).await {
+                    Ok((socket, _)) => {
+                        *state.write().unwrap() = ConnectionState::Connected;
+                        let (mut write, mut read) = socket.split();
+
+                        // Resubscribe to every topic this client had registered before
+                        // the (re)connect, so callers don't have to redo it themselves.
+                        for topic in subscriptions.lock().unwrap().iter() {
+                            if let Ok(json) = serde_json::to_string(topic) {
+                                let _ = write.send(WsMessage::Text(json)).await;
+                            }
+                        }
+
+                        let mut ping_timer = tokio::time::interval(PING_INTERVAL);
+                        loop {
+                            tokio::select! {
+                                _ = ping_timer.tick() => {
+                                    if write.send(WsMessage::Ping(Vec::new())).await.is_err() {
+                                        break;
+                                    }
+                                }
+                                msg = read.next() => {
+                                    match msg {
+                                        Some(Ok(WsMessage::Text(text))) => {
+                                            if let Ok(parsed) = serde_json::from_str::<StreamMessage>(&text) {
+                                                let _ = tx.send(parsed);
+                                            }
+                                        }
+                                        Some(Ok(WsMessage::Pong(_))) => {}
+                                        Some(Ok(WsMessage::Close(_))) | None => break,
+                                        Some(Err(_)) => break,
+                                        _ => {}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Err(_) => {}
+                }
+
+                *state.write().unwrap() = ConnectionState::Disconnected;
+                if shutdown.load(Ordering::SeqCst) {
+                    break;
+                }
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        });
+    }
+}
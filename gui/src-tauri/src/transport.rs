@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Patch,
+}
+
+/// A transport-agnostic description of a request, built by every `ApiClient`
+/// method and handed to a [`RequestExecutor`] instead of a `reqwest::RequestBuilder`.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: HttpMethod,
+    pub url: String,
+    pub headers: HashMap<String, String>,
+    pub body: Option<Value>,
+}
+
+impl HttpRequest {
+    pub fn get(url: impl Into<String>) -> Self {
+        Self { method: HttpMethod::Get, url: url.into(), headers: HashMap::new(), body: None }
+    }
+
+    pub fn post(url: impl Into<String>) -> Self {
+        Self { method: HttpMethod::Post, url: url.into(), headers: HashMap::new(), body: None }
+    }
+
+    pub fn with_json(mut self, body: &impl serde::Serialize) -> Self {
+        self.body = serde_json::to_value(body).ok();
+        self
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// A transport-agnostic response. `json`/`text` mirror the subset of
+/// `reqwest::Response` the client actually uses.
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, TransportError> {
+        serde_json::from_slice(&self.body).map_err(TransportError::Decode)
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransportError {
+    #[error("transport error: {0}")]
+    Network(String),
+
+    #[error("request timed out")]
+    Timeout,
+
+    #[error("failed to decode response body: {0}")]
+    Decode(#[from] serde_json::Error),
+}
+
+/// Decouples `ApiClient` from `reqwest` so callers can inject their own
+/// transport — a mock for unit tests, middleware that adds tracing, a proxy,
+/// etc — without the crate owning those concerns.
+#[async_trait]
+pub trait RequestExecutor: Send + Sync {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, TransportError>;
+}
+
+/// Default executor, backed by a plain `reqwest::Client`.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestExecutor {
+    client: reqwest::Client,
+}
+
+impl ReqwestExecutor {
+    /// Wraps an already-configured `reqwest::Client` (timeouts, default
+    /// headers, etc.) — see [`crate::builder::ClientBuilder`].
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl RequestExecutor for ReqwestExecutor {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        let mut builder = match request.method {
+            HttpMethod::Get => self.client.get(&request.url),
+            HttpMethod::Post => self.client.post(&request.url),
+            HttpMethod::Put => self.client.put(&request.url),
+            HttpMethod::Delete => self.client.delete(&request.url),
+            HttpMethod::Patch => self.client.patch(&request.url),
+        };
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = &request.body {
+            builder = builder.json(body);
+        }
+
+        let response = builder.send().await.map_err(|e| {
+            if e.is_timeout() {
+                TransportError::Timeout
+            } else {
+                TransportError::Network(e.to_string())
+            }
+        })?;
+
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| value.to_str().ok().map(|v| (name.to_string(), v.to_string())))
+            .collect();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| TransportError::Network(e.to_string()))?
+            .to_vec();
+
+        Ok(HttpResponse { status, headers, body })
+    }
+}
@@ -0,0 +1,113 @@
+use crate::config::AppState;
+use tauri::{
+    AppHandle, CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu,
+    SystemTrayMenuItem,
+};
+
+const TOGGLE_AUTO_REPRICING: &str = "toggle_auto_repricing";
+const TOGGLE_LISTING_RULE: &str = "toggle_listing_rule";
+const SYNC_INVENTORY: &str = "sync_inventory";
+const RUN_CLEARANCE_DRY_RUN: &str = "run_clearance_dry_run";
+const QUIT: &str = "quit";
+
+/// Builds the tray menu shell. Checkmarks are seeded from the backend's actual
+/// auto-repricing/auto-listing status once the app finishes starting up, via
+/// [`refresh_tray_state`] — the tray has to exist before that status is known.
+pub fn build() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new(TOGGLE_AUTO_REPRICING, "Auto-Repricing").disabled())
+        .add_item(CustomMenuItem::new(TOGGLE_LISTING_RULE, "Auto-Listing").disabled())
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new(SYNC_INVENTORY, "Sync inventory from StockX"))
+        .add_item(CustomMenuItem::new(RUN_CLEARANCE_DRY_RUN, "Run clearance (dry run)"))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new(QUIT, "Quit"));
+
+    SystemTray::new().with_menu(menu).with_tooltip("soleflip — checking status…")
+}
+
+pub fn handle_event(app: &AppHandle, event: SystemTrayEvent) {
+    let SystemTrayEvent::MenuItemClick { id, .. } = event else { return };
+    let app = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<AppState>();
+        let client = state.client();
+
+        match id.as_str() {
+            TOGGLE_AUTO_REPRICING => {
+                if let Ok(status) = client.get_auto_repricing_status().await {
+                    let _ = client.toggle_auto_repricing(!status.enabled).await;
+                    let _ = app.emit_all("tray://auto_repricing_toggled", !status.enabled);
+                    refresh_tray_state(&app).await;
+                }
+            }
+            TOGGLE_LISTING_RULE => {
+                if let Ok(listing_status) = client.get_auto_listing_status().await {
+                    if let Some(rule) = listing_status.rules.first() {
+                        let _ = client.toggle_listing_rule(rule.name.clone(), !rule.active).await;
+                        let _ = app.emit_all("tray://listing_rule_toggled", rule.name.clone());
+                        refresh_tray_state(&app).await;
+                    }
+                }
+            }
+            SYNC_INVENTORY => {
+                let _ = client.sync_inventory_from_stockx().await;
+                let _ = app.emit_all("tray://sync_started", ());
+            }
+            RUN_CLEARANCE_DRY_RUN => {
+                let risk_levels = vec!["high".to_string(), "critical".to_string()];
+                if let Ok(execution) = client.execute_clearance(risk_levels, 50, true).await {
+                    let _ = app.emit_all("tray://clearance_dry_run_complete", execution);
+                }
+            }
+            QUIT => {
+                app.exit(0);
+            }
+            _ => {}
+        }
+    });
+}
+
+/// Refreshes the tray tooltip from the backend health check (the same
+/// database-health test the `get_system_status` command applies; the icon is
+/// left unchanged) and the menu labels and checkmarks from the
+/// auto-repricing/auto-listing status endpoints. Called
+/// once at startup and again after any toggle so the tray never goes stale.
+pub async fn refresh_tray_state(app: &AppHandle) {
+    let state = app.state::<AppState>();
+    let client = state.client();
+    let tray_handle = app.tray_handle();
+
+    if let Ok(health) = client.health_check().await {
+        let database_healthy = health
+            .components
+            .get("database")
+            .and_then(|db| db.get("status"))
+            .and_then(|status| status.as_str())
+            .map_or(false, |status| status == "healthy");
+
+        let tooltip = if database_healthy {
+            "soleflip — connected"
+        } else {
+            "soleflip — backend degraded"
+        };
+        let _ = tray_handle.set_tooltip(tooltip);
+    } else {
+        let _ = tray_handle.set_tooltip("soleflip — backend unreachable");
+    }
+
+    if let Ok(status) = client.get_auto_repricing_status().await {
+        let label = format!("Auto-Repricing ({})", if status.enabled { "on" } else { "off" });
+        let _ = tray_handle.get_item(TOGGLE_AUTO_REPRICING).set_title(&label);
+        let _ = tray_handle.get_item(TOGGLE_AUTO_REPRICING).set_selected(status.enabled);
+        let _ = tray_handle.get_item(TOGGLE_AUTO_REPRICING).set_enabled(true);
+    }
+
+    if let Ok(status) = client.get_auto_listing_status().await {
+        let label = format!("Auto-Listing ({})", if status.enabled { "on" } else { "off" });
+        let _ = tray_handle.get_item(TOGGLE_LISTING_RULE).set_title(&label);
+        let _ = tray_handle.get_item(TOGGLE_LISTING_RULE).set_selected(status.enabled);
+        let _ = tray_handle.get_item(TOGGLE_LISTING_RULE).set_enabled(true);
+    }
+}